@@ -1,3 +1,5 @@
 mod ast;
 
+pub mod schema;
+
 pub use ast::{AstDatabase, AstDatabaseStorage, ScriptTagId, ScriptTag};
@@ -0,0 +1,372 @@
+//! A table of the elements and attributes defined by HTML5, with the content model of each element.
+//!
+//! See https://html.spec.whatwg.org/multipage/dom.html#content-models
+//!
+//! The table only covers the parts of the content models which can be checked from the static
+//! structure of a document, so some constraints are more permissive than the specification
+//! (e.g. `<link>` and `<meta>` are allowed anywhere that phrasing content is allowed).
+
+/// A set of content categories (e.g. `FLOW | PHRASING`).
+pub type Categories = u16;
+
+pub const METADATA: Categories = 1 << 0;
+pub const FLOW: Categories = 1 << 1;
+pub const SECTIONING: Categories = 1 << 2;
+pub const HEADING: Categories = 1 << 3;
+pub const PHRASING: Categories = 1 << 4;
+pub const EMBEDDED: Categories = 1 << 5;
+pub const INTERACTIVE: Categories = 1 << 6;
+/// Elements which may be used anywhere, because they don't represent anything (i.e. `<script>` and `<template>`).
+pub const SCRIPT_SUPPORTING: Categories = 1 << 7;
+
+/// The content which an element may contain.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Content {
+    /// A void element, which can't have any content or an end tag (e.g. `<br>`).
+    Void,
+    /// An element which must be empty, but still has an end tag (e.g. `<iframe>`).
+    Nothing,
+    /// Only text (e.g. `<title>` or `<textarea>`).
+    Text,
+    /// Elements in any of the categories and text, if the categories include phrasing content.
+    Categories(Categories),
+    /// Only the listed elements and script-supporting elements (e.g. `<li>` in a `<ul>`).
+    Elements(&'static [&'static str]),
+    /// The content model of the parent element (e.g. `<a>` or `<ins>`).
+    Transparent,
+    /// Anything, because the content isn't html (e.g. `<svg>`) or isn't rendered (e.g. `<template>`).
+    Any,
+}
+
+#[derive(Debug)]
+pub struct ElementSchema {
+    pub name: &'static str,
+    /// The categories of content which the element belongs to.
+    pub categories: Categories,
+    pub content: Content,
+    /// The only elements which the element may be a child of, or empty if any element with a
+    /// matching content model may contain it (e.g. `<li>` may only be a child of `<ul>`, `<ol>` or `<menu>`).
+    pub parents: &'static [&'static str],
+    /// Categories of content which can't be descendants of the element (e.g. interactive content in `<a>`).
+    pub excludes: Categories,
+    /// Elements which can't be descendants of the element (e.g. `<form>` in a `<form>`).
+    pub excluded_elements: &'static [&'static str],
+    /// Attributes of the element, in addition to the global attributes.
+    pub attributes: &'static [&'static str],
+}
+
+impl ElementSchema {
+    const fn new(name: &'static str, categories: Categories, content: Content) -> ElementSchema {
+        ElementSchema {
+            name,
+            categories,
+            content,
+            parents: &[],
+            excludes: 0,
+            excluded_elements: &[],
+            attributes: &[],
+        }
+    }
+
+    const fn parents(self, parents: &'static [&'static str]) -> ElementSchema {
+        ElementSchema { parents, ..self }
+    }
+
+    const fn excludes(self, excludes: Categories, excluded_elements: &'static [&'static str]) -> ElementSchema {
+        ElementSchema { excludes, excluded_elements, ..self }
+    }
+
+    const fn attributes(self, attributes: &'static [&'static str]) -> ElementSchema {
+        ElementSchema { attributes, ..self }
+    }
+
+    /// The categories of the element with some attributes, because some elements are only interactive
+    /// with some attributes (e.g. `<a>` with an `href`).
+    ///
+    /// The `attribute` function should return the value of an attribute, or `Some("")` if its value is unknown.
+    pub fn categories_with<'a>(&self, attribute: impl Fn(&str) -> Option<&'a str>) -> Categories {
+        let interactive = match self.name {
+            "a" => attribute("href").is_some(),
+            "audio" | "video" => attribute("controls").is_some(),
+            "img" | "object" => attribute("usemap").is_some(),
+            "input" => attribute("type").map(|type_| !type_.eq_ignore_ascii_case("hidden")).unwrap_or(true),
+            _ => return self.categories,
+        };
+        if interactive {
+            self.categories | INTERACTIVE
+        } else {
+            self.categories & !INTERACTIVE
+        }
+    }
+
+    /// Returns true if the element may have an attribute, including global attributes (e.g. `id`),
+    /// custom data attributes (e.g. `data-id`) and event handlers (e.g. `onclick`).
+    pub fn has_attribute(&self, name: &str) -> bool {
+        is_global_attribute(name) || self.attributes.contains(&name)
+    }
+}
+
+/// Finds the schema of an element by its tag name (which isn't case sensitive).
+pub fn element(name: &str) -> Option<&'static ElementSchema> {
+    ELEMENTS.iter().find(|element| element.name.eq_ignore_ascii_case(name))
+}
+
+/// Returns true if an element can't have any content or an end tag (e.g. `<br>`).
+pub fn is_void_element(name: &str) -> bool {
+    element(name).map(|element| element.content == Content::Void).unwrap_or(false)
+}
+
+/// Returns true if any element may have an attribute (e.g. `id`, `aria-label`, `data-id` or `onclick`).
+pub fn is_global_attribute(name: &str) -> bool {
+    GLOBAL_ATTRIBUTES.contains(&name) ||
+        name.starts_with("aria-") ||
+        name.starts_with("data-") ||
+        (name.starts_with("on") && name.len() > 2 && name[2..].chars().all(|c| c.is_ascii_lowercase()))
+}
+
+/// Describes the categories for messages (e.g. `flow content` or `phrasing content`).
+pub fn describe(categories: Categories) -> String {
+    let names = [
+        (METADATA, "metadata content"),
+        (FLOW, "flow content"),
+        (SECTIONING, "sectioning content"),
+        (HEADING, "heading content"),
+        (PHRASING, "phrasing content"),
+        (EMBEDDED, "embedded content"),
+        (INTERACTIVE, "interactive content"),
+        (SCRIPT_SUPPORTING, "script-supporting elements"),
+    ];
+    let names = names.iter().filter(|(category, _)| categories & category != 0).map(|(_, name)| *name).collect::<Vec<_>>();
+    match names.split_last() {
+        Some((last, [])) => last.to_string(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => "nothing".into(),
+    }
+}
+
+/// Attributes which may be used on any element.
+pub const GLOBAL_ATTRIBUTES: &[&str] = &[
+    "accesskey", "autocapitalize", "autofocus", "class", "contenteditable", "dir", "draggable", "enterkeyhint",
+    "hidden", "id", "inert", "inputmode", "is", "itemid", "itemprop", "itemref", "itemscope", "itemtype", "lang",
+    "nonce", "part", "role", "slot", "spellcheck", "style", "tabindex", "title", "translate", "xml:lang", "xml:space",
+];
+
+const FLOW_PHRASING: Categories = FLOW | PHRASING;
+
+/// The elements defined by HTML5.
+pub const ELEMENTS: &[ElementSchema] = &[
+    // The document element and metadata
+    ElementSchema::new("html", 0, Content::Elements(&["head", "body"])).attributes(&["manifest", "xmlns"]),
+    ElementSchema::new("head", 0, Content::Categories(METADATA)).parents(&["html"]),
+    ElementSchema::new("title", METADATA, Content::Text),
+    ElementSchema::new("base", METADATA, Content::Void).attributes(&["href", "target"]),
+    ElementSchema::new("link", METADATA | FLOW_PHRASING, Content::Void).attributes(&[
+        "as", "color", "crossorigin", "disabled", "href", "hreflang", "imagesizes", "imagesrcset", "integrity",
+        "media", "referrerpolicy", "rel", "sizes", "type",
+    ]),
+    ElementSchema::new("meta", METADATA | FLOW_PHRASING, Content::Void).attributes(&["charset", "content", "http-equiv", "name"]),
+    ElementSchema::new("style", METADATA, Content::Text).attributes(&["media", "type"]),
+
+    // Sections
+    ElementSchema::new("body", 0, Content::Categories(FLOW)).parents(&["html"]),
+    ElementSchema::new("article", FLOW | SECTIONING, Content::Categories(FLOW)),
+    ElementSchema::new("section", FLOW | SECTIONING, Content::Categories(FLOW)),
+    ElementSchema::new("nav", FLOW | SECTIONING, Content::Categories(FLOW)),
+    ElementSchema::new("aside", FLOW | SECTIONING, Content::Categories(FLOW)),
+    ElementSchema::new("h1", FLOW | HEADING, Content::Categories(PHRASING)),
+    ElementSchema::new("h2", FLOW | HEADING, Content::Categories(PHRASING)),
+    ElementSchema::new("h3", FLOW | HEADING, Content::Categories(PHRASING)),
+    ElementSchema::new("h4", FLOW | HEADING, Content::Categories(PHRASING)),
+    ElementSchema::new("h5", FLOW | HEADING, Content::Categories(PHRASING)),
+    ElementSchema::new("h6", FLOW | HEADING, Content::Categories(PHRASING)),
+    ElementSchema::new("hgroup", FLOW | HEADING, Content::Elements(&["h1", "h2", "h3", "h4", "h5", "h6", "p"])),
+    ElementSchema::new("header", FLOW, Content::Categories(FLOW)).excludes(0, &["header", "footer"]),
+    ElementSchema::new("footer", FLOW, Content::Categories(FLOW)).excludes(0, &["header", "footer"]),
+    ElementSchema::new("address", FLOW, Content::Categories(FLOW))
+        .excludes(HEADING | SECTIONING, &["address", "header", "footer"]),
+
+    // Grouping content
+    ElementSchema::new("p", FLOW, Content::Categories(PHRASING)),
+    ElementSchema::new("hr", FLOW, Content::Void),
+    ElementSchema::new("pre", FLOW, Content::Categories(PHRASING)),
+    ElementSchema::new("blockquote", FLOW | SECTIONING, Content::Categories(FLOW)).attributes(&["cite"]),
+    ElementSchema::new("ol", FLOW, Content::Elements(&["li"])).attributes(&["reversed", "start", "type"]),
+    ElementSchema::new("ul", FLOW, Content::Elements(&["li"])),
+    ElementSchema::new("menu", FLOW, Content::Elements(&["li"])),
+    ElementSchema::new("li", 0, Content::Categories(FLOW)).parents(&["ol", "ul", "menu"]).attributes(&["value"]),
+    ElementSchema::new("dl", FLOW, Content::Elements(&["dt", "dd", "div"])),
+    ElementSchema::new("dt", 0, Content::Categories(FLOW))
+        .parents(&["dl", "div"])
+        .excludes(HEADING | SECTIONING, &["header", "footer"]),
+    ElementSchema::new("dd", 0, Content::Categories(FLOW)).parents(&["dl", "div"]),
+    ElementSchema::new("figure", FLOW | SECTIONING, Content::Categories(FLOW)),
+    ElementSchema::new("figcaption", 0, Content::Categories(FLOW)).parents(&["figure"]),
+    ElementSchema::new("main", FLOW, Content::Categories(FLOW)),
+    ElementSchema::new("div", FLOW, Content::Categories(FLOW)),
+
+    // Text-level semantics
+    ElementSchema::new("a", FLOW_PHRASING | INTERACTIVE, Content::Transparent)
+        .excludes(INTERACTIVE, &["a"])
+        .attributes(&["download", "href", "hreflang", "ping", "referrerpolicy", "rel", "target", "type"]),
+    ElementSchema::new("em", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("strong", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("small", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("s", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("cite", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("q", FLOW_PHRASING, Content::Categories(PHRASING)).attributes(&["cite"]),
+    ElementSchema::new("dfn", FLOW_PHRASING, Content::Categories(PHRASING)).excludes(0, &["dfn"]),
+    ElementSchema::new("abbr", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("ruby", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("rt", 0, Content::Categories(PHRASING)).parents(&["ruby"]),
+    ElementSchema::new("rp", 0, Content::Text).parents(&["ruby"]),
+    ElementSchema::new("data", FLOW_PHRASING, Content::Categories(PHRASING)).attributes(&["value"]),
+    ElementSchema::new("time", FLOW_PHRASING, Content::Categories(PHRASING)).attributes(&["datetime"]),
+    ElementSchema::new("code", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("var", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("samp", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("kbd", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("sub", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("sup", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("i", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("b", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("u", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("mark", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("bdi", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("bdo", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("span", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("br", FLOW_PHRASING, Content::Void),
+    ElementSchema::new("wbr", FLOW_PHRASING, Content::Void),
+
+    // Edits
+    ElementSchema::new("ins", FLOW_PHRASING, Content::Transparent).attributes(&["cite", "datetime"]),
+    ElementSchema::new("del", FLOW_PHRASING, Content::Transparent).attributes(&["cite", "datetime"]),
+
+    // Embedded content
+    ElementSchema::new("picture", FLOW_PHRASING | EMBEDDED, Content::Elements(&["source", "img"])),
+    ElementSchema::new("source", 0, Content::Void)
+        .parents(&["picture", "video", "audio"])
+        .attributes(&["media", "sizes", "src", "srcset", "type"]),
+    ElementSchema::new("img", FLOW_PHRASING | EMBEDDED, Content::Void).attributes(&[
+        "alt", "crossorigin", "decoding", "height", "ismap", "loading", "referrerpolicy", "sizes", "src", "srcset",
+        "usemap", "width",
+    ]),
+    ElementSchema::new("iframe", FLOW_PHRASING | EMBEDDED | INTERACTIVE, Content::Nothing).attributes(&[
+        "allow", "allowfullscreen", "allowpaymentrequest", "height", "loading", "name", "referrerpolicy", "sandbox",
+        "src", "srcdoc", "width",
+    ]),
+    ElementSchema::new("embed", FLOW_PHRASING | EMBEDDED | INTERACTIVE, Content::Void)
+        .attributes(&["height", "src", "type", "width"]),
+    ElementSchema::new("object", FLOW_PHRASING | EMBEDDED, Content::Transparent)
+        .attributes(&["data", "form", "height", "name", "type", "typemustmatch", "usemap", "width"]),
+    ElementSchema::new("param", 0, Content::Void).parents(&["object"]).attributes(&["name", "value"]),
+    ElementSchema::new("video", FLOW_PHRASING | EMBEDDED, Content::Transparent)
+        .excludes(0, &["audio", "video"])
+        .attributes(&[
+            "autoplay", "controls", "crossorigin", "height", "loop", "muted", "playsinline", "poster", "preload", "src",
+            "width",
+        ]),
+    ElementSchema::new("audio", FLOW_PHRASING | EMBEDDED, Content::Transparent)
+        .excludes(0, &["audio", "video"])
+        .attributes(&["autoplay", "controls", "crossorigin", "loop", "muted", "preload", "src"]),
+    ElementSchema::new("track", 0, Content::Void)
+        .parents(&["video", "audio"])
+        .attributes(&["default", "kind", "label", "src", "srclang"]),
+    ElementSchema::new("map", FLOW_PHRASING, Content::Transparent).attributes(&["name"]),
+    ElementSchema::new("area", FLOW_PHRASING, Content::Void)
+        .attributes(&["alt", "coords", "download", "href", "ping", "referrerpolicy", "rel", "shape", "target"]),
+    ElementSchema::new("svg", FLOW_PHRASING | EMBEDDED, Content::Any),
+    ElementSchema::new("math", FLOW_PHRASING | EMBEDDED, Content::Any),
+
+    // Tabular data
+    ElementSchema::new("table", FLOW, Content::Elements(&["caption", "colgroup", "thead", "tbody", "tfoot", "tr"])),
+    ElementSchema::new("caption", 0, Content::Categories(FLOW)).parents(&["table"]).excludes(0, &["table"]),
+    ElementSchema::new("colgroup", 0, Content::Elements(&["col"])).parents(&["table"]).attributes(&["span"]),
+    ElementSchema::new("col", 0, Content::Void).parents(&["colgroup"]).attributes(&["span"]),
+    ElementSchema::new("thead", 0, Content::Elements(&["tr"])).parents(&["table"]),
+    ElementSchema::new("tbody", 0, Content::Elements(&["tr"])).parents(&["table"]),
+    ElementSchema::new("tfoot", 0, Content::Elements(&["tr"])).parents(&["table"]),
+    ElementSchema::new("tr", 0, Content::Elements(&["td", "th"])).parents(&["table", "thead", "tbody", "tfoot"]),
+    ElementSchema::new("td", 0, Content::Categories(FLOW)).parents(&["tr"]).attributes(&["colspan", "headers", "rowspan"]),
+    ElementSchema::new("th", 0, Content::Categories(FLOW))
+        .parents(&["tr"])
+        .excludes(HEADING | SECTIONING, &["header", "footer"])
+        .attributes(&["abbr", "colspan", "headers", "rowspan", "scope"]),
+
+    // Forms
+    ElementSchema::new("form", FLOW, Content::Categories(FLOW)).excludes(0, &["form"]).attributes(&[
+        "accept-charset", "action", "autocomplete", "enctype", "method", "name", "novalidate", "rel", "target",
+    ]),
+    ElementSchema::new("label", FLOW_PHRASING | INTERACTIVE, Content::Categories(PHRASING))
+        .excludes(0, &["label"])
+        .attributes(&["for"]),
+    ElementSchema::new("input", FLOW_PHRASING | INTERACTIVE, Content::Void).attributes(&[
+        "accept", "alt", "autocomplete", "checked", "dirname", "disabled", "form", "formaction", "formenctype",
+        "formmethod", "formnovalidate", "formtarget", "height", "list", "max", "maxlength", "min", "minlength",
+        "multiple", "name", "pattern", "placeholder", "readonly", "required", "size", "src", "step", "type", "value",
+        "width",
+    ]),
+    ElementSchema::new("button", FLOW_PHRASING | INTERACTIVE, Content::Categories(PHRASING))
+        .excludes(INTERACTIVE, &[])
+        .attributes(&[
+            "disabled", "form", "formaction", "formenctype", "formmethod", "formnovalidate", "formtarget", "name",
+            "type", "value",
+        ]),
+    ElementSchema::new("select", FLOW_PHRASING | INTERACTIVE, Content::Elements(&["option", "optgroup"]))
+        .attributes(&["autocomplete", "disabled", "form", "multiple", "name", "required", "size"]),
+    ElementSchema::new("datalist", FLOW_PHRASING, Content::Categories(PHRASING)),
+    ElementSchema::new("optgroup", 0, Content::Elements(&["option"])).parents(&["select"]).attributes(&["disabled", "label"]),
+    ElementSchema::new("option", 0, Content::Text)
+        .parents(&["select", "datalist", "optgroup"])
+        .attributes(&["disabled", "label", "selected", "value"]),
+    ElementSchema::new("textarea", FLOW_PHRASING | INTERACTIVE, Content::Text).attributes(&[
+        "autocomplete", "cols", "dirname", "disabled", "form", "maxlength", "minlength", "name", "placeholder",
+        "readonly", "required", "rows", "wrap",
+    ]),
+    ElementSchema::new("output", FLOW_PHRASING, Content::Categories(PHRASING)).attributes(&["for", "form", "name"]),
+    ElementSchema::new("progress", FLOW_PHRASING, Content::Categories(PHRASING))
+        .excludes(0, &["progress"])
+        .attributes(&["max", "value"]),
+    ElementSchema::new("meter", FLOW_PHRASING, Content::Categories(PHRASING))
+        .excludes(0, &["meter"])
+        .attributes(&["high", "low", "max", "min", "optimum", "value"]),
+    ElementSchema::new("fieldset", FLOW, Content::Categories(FLOW)).attributes(&["disabled", "form", "name"]),
+    ElementSchema::new("legend", 0, Content::Categories(PHRASING | HEADING)).parents(&["fieldset"]),
+
+    // Interactive elements
+    ElementSchema::new("details", FLOW | SECTIONING | INTERACTIVE, Content::Categories(FLOW)).attributes(&["open"]),
+    ElementSchema::new("summary", 0, Content::Categories(PHRASING | HEADING)).parents(&["details"]),
+    ElementSchema::new("dialog", FLOW | SECTIONING, Content::Categories(FLOW)).attributes(&["open"]),
+
+    // Scripting
+    ElementSchema::new("script", METADATA | FLOW_PHRASING | SCRIPT_SUPPORTING, Content::Text).attributes(&[
+        "async", "charset", "crossorigin", "defer", "integrity", "nomodule", "referrerpolicy", "src", "type",
+    ]),
+    ElementSchema::new("noscript", METADATA | FLOW_PHRASING, Content::Any),
+    ElementSchema::new("template", METADATA | FLOW_PHRASING | SCRIPT_SUPPORTING, Content::Any),
+    ElementSchema::new("slot", FLOW_PHRASING, Content::Transparent).attributes(&["name"]),
+    ElementSchema::new("canvas", FLOW_PHRASING | EMBEDDED, Content::Transparent)
+        .excludes(INTERACTIVE, &[])
+        .attributes(&["height", "width"]),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_elements() {
+        for (i, element) in ELEMENTS.iter().enumerate() {
+            assert!(!ELEMENTS[..i].iter().any(|other| other.name == element.name), "duplicate element `{}`", element.name);
+            for name in element.parents {
+                assert!(super::element(name).is_some(), "unknown parent `{}` of `{}`", name, element.name);
+            }
+        }
+        assert!(["h1", "h2", "h3", "h4", "h5", "h6"].iter().all(|name| element(name).map(|h| h.categories & HEADING != 0).unwrap_or(false)));
+        assert!(is_void_element("BR"));
+        assert!(!is_void_element("p"));
+        assert!(element("a").unwrap().has_attribute("href"));
+        assert!(element("div").unwrap().has_attribute("onclick"));
+        assert!(!element("div").unwrap().has_attribute("href"));
+        assert_eq!(describe(FLOW | PHRASING), "flow content or phrasing content");
+    }
+}
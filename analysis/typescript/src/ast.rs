@@ -32,6 +32,18 @@ pub fn typescript_source_map(db: &impl AstDatabase, file_id: SourceId) -> Arc<As
             Some(&node.syntax)
         } else if let Some(node) = ts::FunctionExpression::cast(node) {
             Some(&node.syntax)
+        } else if let Some(node) = ts::ArrowFunctionExpression::cast(node) {
+            Some(&node.syntax)
+        } else if let Some(node) = ts::AssignmentExpression::cast(node) {
+            Some(&node.syntax)
+        } else if let Some(node) = ts::CallExpression::cast(node) {
+            Some(&node.syntax)
+        } else if let Some(node) = ts::NewExpression::cast(node) {
+            Some(&node.syntax)
+        } else if let Some(node) = ts::ObjectExpression::cast(node) {
+            Some(&node.syntax)
+        } else if let Some(node) = ts::ImportDeclaration::cast(node) {
+            Some(&node.syntax)
         } else {
             None
         }
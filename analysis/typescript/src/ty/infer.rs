@@ -1,5 +1,5 @@
-use crate::ty::{InterfaceTy, PropertyDef, Ty, TypeOf};
-use code_grammar::{AstNode, SmolStr};
+use crate::ty::{FunctionSig, InterfaceTy, PropertyDef, Ty, TypeOf};
+use code_grammar::{AstNode, SmolStr, SyntaxNode, SyntaxNodeExt};
 use typescript_grammar::ast;
 use typescript_grammar::syntax_kind::*;
 
@@ -20,7 +20,7 @@ pub fn infer_expression_type(expr: &ast::Expression) -> Ty {
         ast::ExpressionKind::ThisExpression(_node) => Ty::Any, // TODO: Lookup `self` type (maybe leave inference placeholder?)
         ast::ExpressionKind::ArrayExpression(node) => infer_array_expression_type(node),
         ast::ExpressionKind::ObjectExpression(node) => infer_object_expression_type(node),
-        ast::ExpressionKind::FunctionExpression(_) => Ty::Hint(TypeOf::Function),
+        ast::ExpressionKind::FunctionExpression(node) => infer_function_type(node.params(), &node.syntax),
         ast::ExpressionKind::UnaryExpression(node) => infer_unary_expression_type(node),
         ast::ExpressionKind::UpdateExpression(_) => Ty::Number,
         ast::ExpressionKind::BinaryExpression(_node) => Ty::Any, // TODO: Implement
@@ -31,7 +31,14 @@ pub fn infer_expression_type(expr: &ast::Expression) -> Ty {
         ast::ExpressionKind::CallExpression(_node) => Ty::Any, // TODO: Implement
         ast::ExpressionKind::NewExpression(_) => Ty::Hint(TypeOf::Object),
         ast::ExpressionKind::SequenceExpression(node) => node.expressions().last().map(infer_expression_type).unwrap_or(Ty::Never),
-        ast::ExpressionKind::ArrowFunctionExpression(_) => Ty::Hint(TypeOf::Function),
+        ast::ExpressionKind::ArrowFunctionExpression(node) => {
+            // N.B. an expression body may also be cast to a pattern (e.g. `x => x`)
+            let body = match node.body() {
+                Some(ast::ArrowFunctionBody::Expression(expr)) => Some(expr.syntax.range()),
+                _ => None,
+            };
+            infer_function_type(node.params().filter(|param| Some(param.syntax.range()) != body), &node.syntax)
+        }
         ast::ExpressionKind::YieldExpression(_node) => Ty::Any, // TODO: Implement
         ast::ExpressionKind::TemplateLiteral(_) => Ty::String,
         ast::ExpressionKind::TaggedTemplateExpression(_node) => Ty::Any, // TODO: Implement
@@ -84,6 +91,32 @@ pub(crate) fn infer_object_expression_type(expr: &ast::ObjectExpression) -> Ty {
     Ty::from(object)
 }
 
+pub(crate) fn infer_function_type<'a>(params: impl Iterator<Item = &'a ast::Pattern>, func: &SyntaxNode) -> Ty {
+    let mut sig = FunctionSig { inputs: Vec::new(), required: 0, variadic: false, output: Ty::Any };
+    for param in params {
+        match param.kind() {
+            ast::PatternKind::RestElement(_) => sig.variadic = true,
+            ast::PatternKind::AssignmentPattern(_) => sig.inputs.push(Ty::Any),
+            _ => {
+                if sig.required == sig.inputs.len() {
+                    sig.required += 1;
+                }
+                sig.inputs.push(Ty::Any);
+            }
+        }
+    }
+
+    // N.B. the function may read any number of arguments with `arguments[idx]`
+    if func.descendants().filter_map(ast::Identifier::cast).any(|ident| ident.name() == "arguments") {
+        sig.variadic = true;
+    }
+
+    let mut function = InterfaceTy::default();
+    function.callable = Some(sig);
+    function.typeof_ = Some(vec![TypeOf::Function].into());
+    Ty::from(function)
+}
+
 pub(crate) fn infer_unary_expression_type(expr: &ast::UnaryExpression) -> Ty {
     match expr.syntax.first_token().map(|t| t.kind()) {
         Some(DELETE_KW) => Ty::Boolean,
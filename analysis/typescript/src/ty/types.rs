@@ -60,6 +60,10 @@ pub enum Ty {
 
 
 impl Ty {
+    pub fn as_function(&self) -> Option<&FunctionSig> {
+        self.as_interface().and_then(|ty| ty.callable.as_ref())
+    }
+
     pub fn as_interface(&self) -> Option<&InterfaceTy> {
         match self {
             Ty::Interface(ty) => Some(ty),
@@ -136,11 +140,15 @@ pub struct PropertyDef {
 /// Signature of a function type.
 #[derive(Clone, Debug)]
 pub struct FunctionSig {
-    // TODO: Handle spread parameters in function signatures
-
     /// The arguments to the function.
     pub inputs: Vec<Ty>,
 
+    /// The number of leading arguments which don't have a default value.
+    pub required: usize,
+
+    /// Whether the function accepts any number of additional arguments (e.g. `...args`).
+    pub variadic: bool,
+
     /// The return type of the function.
     pub output: Ty,
 }
@@ -64,19 +64,11 @@ fn main() -> Result<(), io::Error> {
 
             // Run diagnostics
             let mut total_errors = 0;
-            for (path, file_id) in analysis.files(root_id) {
-                match path.extension() {
-                    Some("js") | Some("ts") | Some("vue") => {
-                        let diagnostics = analysis.diagnostics(file_id.into());
-                        for line in diagnostics {
-                            if line.starts_with("error") {
-                                total_errors += 1;
-                            }
-                            eprintln!("{}", line);
-                        }
-                    },
-                    _ => continue,
-                };
+            for line in check(&analysis, root_id) {
+                if line.starts_with("error") {
+                    total_errors += 1;
+                }
+                eprintln!("{}", line);
             }
             eprintln!("info: found {} error(s)", total_errors);
             std::process::exit(if total_errors > 0 { 1 } else { 0 });
@@ -91,3 +83,41 @@ fn main() -> Result<(), io::Error> {
     }
     Ok(())
 }
+
+/// Computes the diagnostics for each file in the project which can be checked.
+fn check(analysis: &Analysis, root_id: SourceRootId) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (path, file_id) in analysis.files(root_id) {
+        match path.extension() {
+            Some("htm") | Some("html") | Some("js") | Some("ts") | Some("vue") => {
+                lines.extend(analysis.diagnostics(file_id.into()));
+            },
+            _ => continue,
+        };
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_html() {
+        let entrypoint = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/html/package.json");
+        let (mut analysis, vfs) = workspace::load(entrypoint.clone());
+        analysis.set_config(Config::default());
+        let root_id = SourceRootId(vfs.path2file(&entrypoint).unwrap().0);
+        let lines = check(&analysis, root_id);
+        assert!(
+            lines.iter().any(|line| line.contains("[index.html:5:6] `<img>` elements must have an `alt` attribute")),
+            "{:#?}",
+            lines,
+        );
+        assert!(
+            lines.iter().any(|line| line.contains("[index.html:6:16] `<div>` isn't allowed in `<p>`")),
+            "{:#?}",
+            lines,
+        );
+    }
+}
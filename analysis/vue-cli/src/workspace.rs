@@ -7,11 +7,16 @@ pub fn load(entrypoint: PathBuf) -> (Analysis, Vfs) {
     let mut change = SourceChange::new();
 
     let root = find_package_json(&entrypoint).parent().unwrap().to_path_buf();
-    let roots = vec![IncludeFiles::root(root.clone())];
+    let node_modules = root.join("node_modules");
+    let mut roots = vec![IncludeFiles::root(root.clone())];
+    if node_modules.is_dir() {
+        roots.push(IncludeFiles::dependencies(node_modules.clone()));
+    }
     let (mut vfs, vfs_roots) = Vfs::new(roots);
+    let num_roots = vfs_roots.len();
     for r in vfs_roots {
         let vfs_root_path = vfs.root2path(r);
-        let is_local = vfs_root_path.starts_with(&root);
+        let is_local = vfs_root_path.starts_with(&root) && !vfs_root_path.starts_with(&node_modules);
         change.add_root(SourceRootId(r.0.into()), is_local);
     }
 
@@ -31,13 +36,16 @@ pub fn load(entrypoint: PathBuf) -> (Analysis, Vfs) {
 
     // Wait until vfs has loaded all roots
     let receiver = vfs.task_receiver().clone();
+    let mut roots_loaded = 0;
     for task in receiver {
         vfs.handle_task(task);
         let fs_changes = process_changes(&mut vfs);
         analysis.apply_change(fs_changes);
 
-        // TODO: Support more than 1 root
-        break;
+        roots_loaded += 1;
+        if roots_loaded == num_roots {
+            break;
+        }
     }
 
     (analysis, vfs)
@@ -78,25 +86,35 @@ fn process_changes(vfs: &mut Vfs) -> SourceChange {
 /// `IncludeFiles` is used to create a `RootEntry` for VFS
 pub struct IncludeFiles {
     path: PathBuf,
+    /// Whether the root is a `node_modules` directory, rather than the project itself.
+    is_dependencies: bool,
 }
 
 impl IncludeFiles {
     pub fn root(path: PathBuf) -> RootEntry {
-        RootEntry::from(IncludeFiles { path })
+        RootEntry::from(IncludeFiles { path, is_dependencies: false })
+    }
+
+    pub fn dependencies(path: PathBuf) -> RootEntry {
+        RootEntry::from(IncludeFiles { path, is_dependencies: true })
     }
 }
 
 impl Filter for IncludeFiles {
     fn include_dir(&self, dir_path: &RelativePath) -> bool {
         const COMMON_IGNORED_DIRS: &[&str] = &["node_modules", "target", ".git"];
-        let is_ignored = dir_path.components().any(|c| COMMON_IGNORED_DIRS.contains(&c.as_str()));
+        let is_ignored = !self.is_dependencies && dir_path.components().any(|c| COMMON_IGNORED_DIRS.contains(&c.as_str()));
         let hidden = dir_path.components().any(|c| c.as_str().starts_with("."));
         !is_ignored && !hidden
     }
 
     fn include_file(&self, file_path: &RelativePath) -> bool {
+        // N.B. `.json` files are needed to resolve imports (e.g. `package.json` and `tsconfig.json`)
         match file_path.extension() {
+            Some("htm") |
+            Some("html") |
             Some("js") |
+            Some("json") |
             Some("ts") |
             Some("vue") => true,
             _ => false
@@ -124,3 +142,26 @@ fn find_package_json(path: &Path) -> PathBuf {
     }
     panic!("can't find package.json at {}", path.display())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_include_files() {
+        let filter = IncludeFiles { path: PathBuf::from("/project"), is_dependencies: false };
+        assert!(filter.include_file(RelativePath::new("public/index.html")));
+        assert!(filter.include_file(RelativePath::new("public/legacy.htm")));
+        assert!(filter.include_file(RelativePath::new("src/App.vue")));
+        assert!(filter.include_file(RelativePath::new("package.json")));
+        assert!(!filter.include_file(RelativePath::new("src/styles.css")));
+        assert!(!filter.include_file(RelativePath::new("README")));
+
+        assert!(filter.include_dir(RelativePath::new("src/components")));
+        assert!(!filter.include_dir(RelativePath::new("node_modules/vue")));
+        assert!(!filter.include_dir(RelativePath::new(".cache")));
+
+        let dependencies = IncludeFiles { path: PathBuf::from("/project/node_modules"), is_dependencies: true };
+        assert!(dependencies.include_dir(RelativePath::new("vue/node_modules/lodash")));
+    }
+}
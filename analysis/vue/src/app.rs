@@ -1,17 +1,26 @@
-use crate::AstDatabase;
-use code_analysis::{SourceId, SourceRootId};
-use code_grammar::{AstNode, SmolStr, SyntaxNode, WalkEvent};
+use crate::{AstDatabase, ModuleDatabase};
+use code_analysis::{AstId, AstIdMap, FileId, SourceId, SourceRootId};
+use code_grammar::{AstNode, SmolStr, SyntaxElement, SyntaxNode, SyntaxNodeExt, SyntaxToken, TextRange, WalkEvent};
+use typescript_analysis::ty::infer_property_name;
 use typescript_grammar::ast as ts;
-use rustc_hash::FxHashSet;
+use typescript_grammar::syntax_kind::L_PAREN;
+use vue_grammar::syntax_kind::{ELEMENT, TAG_NAME};
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::{fmt, sync::Arc};
 
 #[salsa::query_group(AppDatabaseStorage)]
-pub trait AppDatabase: AstDatabase + typescript_analysis::AstDatabase {
-    /// All components & etc registered with `Vue.{component,filter,mixin}` within the source root.
+pub trait AppDatabase: AstDatabase + ModuleDatabase + typescript_analysis::AstDatabase {
+    /// All components & etc registered with `Vue.{component,directive,filter,mixin}` within the source root.
     fn global_registry(&self, id: SourceRootId) -> Arc<VueRegistry>;
 
-    /// Any components & etc registered to the global `Vue` instance by a particular script.
-    fn script_registry(&self, id: SourceId) -> Arc<VueRegistry>;
+    /// Any components & etc registered to the global `Vue` instance by a particular file.
+    fn script_registry(&self, file_id: FileId) -> Arc<VueRegistry>;
+
+    /// Which components use which other components in their templates, within the source root.
+    fn component_graph(&self, id: SourceRootId) -> Arc<ComponentGraph>;
+
+    /// The keys which a component provides to its descendants with its `provide` option.
+    fn component_provides(&self, file_id: FileId) -> Arc<Provides>;
 }
 
 pub fn global_registry(db: &impl AppDatabase, id: SourceRootId) -> Arc<VueRegistry> {
@@ -19,30 +28,36 @@ pub fn global_registry(db: &impl AppDatabase, id: SourceRootId) -> Arc<VueRegist
 
     // For each supported file in the project, search it for registered components
     let project = db.source_root(id);
-    for (path, file_id) in &project.files {
-        let src_id = match path.extension() {
-            Some("js") | Some("ts") => db.file_source(*file_id),
-            Some("vue") => match db.component_script(db.file_source(*file_id)) {
-                Some((id, _)) => id,
-                None => continue,
-            },
-            _ => continue,
-        };
-        registry.extend(&db.script_registry(src_id));
+    let mut files = project.files.iter().collect::<Vec<_>>();
+    files.sort_by_key(|(path, _)| *path); // N.B. keep global mixins in a consistent order
+    for (_, file_id) in files {
+        registry.extend(&db.script_registry(*file_id));
     }
     Arc::new(registry)
 }
 
-pub fn script_registry(db: &impl AppDatabase, src_id: SourceId) -> Arc<VueRegistry> {
+pub fn script_registry(db: &impl AppDatabase, file_id: FileId) -> Arc<VueRegistry> {
     let mut registry = VueRegistry::default();
-    let node = db.typescript_ast(src_id);
-    if !node.errors().is_empty() {
+    let src_id = match script_source(db, file_id) {
+        Some(src_id) => src_id,
+        None => return Arc::new(registry),
+    };
+    let program = db.typescript_ast(src_id);
+    if !program.errors().is_empty() {
         return Arc::new(registry);
     }
-    for visit in node.syntax.preorder() {
+    let source_map = db.typescript_source_map(src_id);
+    let script = ScriptContext {
+        file_id,
+        src_id,
+        program: &program,
+        source_map: &source_map,
+        aliases: find_plugin_aliases(&program.syntax),
+    };
+    for visit in program.syntax.preorder() {
         match visit {
             WalkEvent::Enter(node) => {
-                try_register(&mut registry, node);
+                try_register(&mut registry, &script, node);
             }
             _ => (),
         }
@@ -50,53 +65,727 @@ pub fn script_registry(db: &impl AppDatabase, src_id: SourceId) -> Arc<VueRegist
     Arc::new(registry)
 }
 
+pub fn component_graph(db: &impl AppDatabase, id: SourceRootId) -> Arc<ComponentGraph> {
+    let mut graph = ComponentGraph::default();
+    let global = db.global_registry(id);
+
+    // Find the components rendered by root instances (e.g. `new Vue({ render: h => h(App) })`)
+    for root in &global.roots {
+        let ast_id = match root.definition {
+            Definition::Expression(ast_id) => ast_id,
+            _ => continue,
+        };
+        let program = db.typescript_ast(ast_id.file_id());
+        let source_map = db.typescript_source_map(ast_id.file_id());
+        let options = match ts::ObjectExpression::downcast(source_map.find_in_root(&program.syntax, ast_id)) {
+            Some(options) => options,
+            None => continue,
+        };
+        let rendered = get_object_property(options, "render")
+            .and_then(returned_expression)
+            .and_then(ts::CallExpression::downcast)
+            .and_then(|call| call.arguments().next())
+            .and_then(|arg| find_definition(db, root.file_id, arg).resolve_file(db, root.file_id));
+        if let Some(rendered) = rendered {
+            graph.roots.insert(rendered, options_provides(options));
+        }
+    }
+
+    // Find the components used in each component's template
+    let project = db.source_root(id);
+    for (path, file_id) in project.files.iter() {
+        if path.extension() != Some("vue") {
+            continue;
+        }
+        let component = db.vue_ast(db.file_source(*file_id));
+        let template = match component.template() {
+            Some(template) => template,
+            None => continue,
+        };
+        for (_, child) in rendered_components(db, *file_id, &template.syntax) {
+            let parents = graph.parents.entry(child).or_default();
+            if !parents.contains(file_id) {
+                parents.push(*file_id);
+            }
+        }
+    }
+    Arc::new(graph)
+}
+
+pub fn component_provides(db: &impl AppDatabase, file_id: FileId) -> Arc<Provides> {
+    let program = match script_source(db, file_id) {
+        Some(src_id) => db.typescript_ast(src_id),
+        None => return Arc::new(Provides::default()),
+    };
+    let provides = component_options(&program).map(options_provides).unwrap_or_default();
+    Arc::new(provides)
+}
+
+/// Finds the keys provided by the `provide` option of a component or root instance.
+fn options_provides(options: &ts::ObjectExpression) -> Provides {
+    let mut provides = Provides::default();
+    let provide = match get_object_property(options, "provide") {
+        Some(provide) => provide,
+        None => return provides,
+    };
+    let object = ts::ObjectExpression::downcast(provide)
+        .or_else(|| returned_expression(provide).and_then(ts::ObjectExpression::downcast));
+    match object {
+        Some(object) => {
+            for prop in object.properties() {
+                match infer_property_name(prop) {
+                    Some(key) => provides.keys.push(key),
+                    None => provides.complete = false, // N.B. e.g. `[Symbol()]: ...`
+                }
+            }
+        }
+        None => provides.complete = false,
+    }
+    provides
+}
+
+/// Finds the elements in a component's template which render another component, e.g. `<my-button>`.
+pub(crate) fn rendered_components<'a>(
+    db: &impl AppDatabase,
+    file_id: FileId,
+    template: &'a SyntaxNode,
+) -> Vec<(&'a SyntaxNode, FileId)> {
+    let global = db.global_registry(db.file_source_root(file_id));
+    let program = script_source(db, file_id).map(|src_id| db.typescript_ast(src_id));
+    let local = program.as_ref()
+        .and_then(|program| component_options(program))
+        .and_then(|options| get_object_property(options, "components"))
+        .and_then(ts::ObjectExpression::downcast)
+        .map(|components| local_components(db, file_id, components))
+        .unwrap_or_default();
+    let mut rendered = Vec::new();
+    for tag in element_tag_names(template) {
+        let name = to_kebab_case(tag.text());
+        let registration = local.iter()
+            .find(|(c, _)| to_kebab_case(c) == name)
+            .map(|(_, registration)| registration)
+            .or_else(|| global.components.iter().find(|(c, _)| to_kebab_case(c) == name).map(|(_, r)| r));
+        if let Some(child) = registration.and_then(|registration| registration.resolve_file(db)) {
+            rendered.push((tag.parent(), child));
+        }
+    }
+    rendered
+}
+
+/// Finds the tag names of the elements in a template.
+pub(crate) fn element_tag_names<'a>(template: &'a SyntaxNode) -> impl Iterator<Item = SyntaxToken<'a>> + 'a {
+    template.descendants()
+        .filter(|node| node.kind() == ELEMENT)
+        .filter_map(|node| node.children_with_tokens().find_map(|el| match el {
+            SyntaxElement::Token(token) if token.kind() == TAG_NAME => Some(token),
+            _ => None,
+        }))
+}
+
+/// Converts a `camelCase` or `PascalCase` name to `kebab-case`, as vue does for directives and components.
+pub(crate) fn to_kebab_case(name: &str) -> String {
+    let mut kebab = String::with_capacity(name.len());
+    for (idx, c) in name.char_indices() {
+        if c.is_uppercase() {
+            if idx > 0 {
+                kebab.push('-');
+            }
+            kebab.extend(c.to_lowercase());
+        } else {
+            kebab.push(c);
+        }
+    }
+    kebab
+}
+
+/// Finds the script of a `.js`, `.ts` or `.vue` file.
+pub(crate) fn script_source(db: &impl AppDatabase, file_id: FileId) -> Option<SourceId> {
+    match db.file_relative_path(file_id).extension() {
+        Some("js") | Some("ts") => Some(db.file_source(file_id)),
+        Some("vue") => db.component_script(db.file_source(file_id)).map(|(id, _)| id),
+        _ => None,
+    }
+}
+
+/// Finds the components registered by a component's `components` option.
+pub(crate) fn local_components(
+    db: &impl AppDatabase,
+    file_id: FileId,
+    components: &ts::ObjectExpression,
+) -> Vec<(SmolStr, Registration)> {
+    with_script_context(db, file_id, |script| {
+        components.properties()
+            .filter_map(|prop| {
+                let name = infer_property_name(prop)?;
+                Some((name, script.register(components.into(), prop.value())))
+            })
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Finds the definition of an expression in a file's script, following local variables and imports.
+pub(crate) fn find_definition(db: &impl AppDatabase, file_id: FileId, expr: &ts::Expression) -> Definition {
+    with_script_context(db, file_id, |script| script.definition(expr)).unwrap_or(Definition::Unknown)
+}
+
+fn with_script_context<T>(db: &impl AppDatabase, file_id: FileId, f: impl FnOnce(&ScriptContext) -> T) -> Option<T> {
+    let src_id = script_source(db, file_id)?;
+    let program = db.typescript_ast(src_id);
+    let source_map = db.typescript_source_map(src_id);
+    let script = ScriptContext {
+        file_id,
+        src_id,
+        program: &program,
+        source_map: &source_map,
+        aliases: Vec::new(),
+    };
+    Some(f(&script))
+}
+
+struct ScriptContext<'a> {
+    file_id: FileId,
+    src_id: SourceId,
+    program: &'a ts::Program,
+    source_map: &'a AstIdMap,
+    /// Names of the global `Vue` instance within plugin functions
+    aliases: Vec<(TextRange, SmolStr)>,
+}
+
+impl ScriptContext<'_> {
+    fn register(&self, registration: &ts::Expression, definition: Option<&ts::Expression>) -> Registration {
+        Registration {
+            file_id: self.file_id,
+            ast_id: self.source_map.ast_id(registration).with_file_id(self.src_id),
+            definition: definition.map(|expr| self.definition(expr)).unwrap_or(Definition::Unknown),
+        }
+    }
+
+    /// Finds the definition of an expression, following local variables and imports.
+    fn definition(&self, expr: &ts::Expression) -> Definition {
+        if let Some(ident) = ts::Identifier::downcast(expr) {
+            return self.resolve_name(ident.name());
+        }
+        if let Some(source) = async_import_source(expr) {
+            let ast_id = self.source_map.ast_id(expr).with_file_id(self.src_id);
+            return Definition::AsyncImport { ast_id, source };
+        }
+        match expr.kind() {
+            ts::ExpressionKind::ArrowFunctionExpression(_) |
+            ts::ExpressionKind::CallExpression(_) |
+            ts::ExpressionKind::ClassExpression(_) |
+            ts::ExpressionKind::FunctionExpression(_) |
+            ts::ExpressionKind::ObjectExpression(_) => {
+                Definition::Expression(self.source_map.ast_id(expr).with_file_id(self.src_id))
+            }
+            _ => Definition::Unknown,
+        }
+    }
+
+    fn resolve_name(&self, name: &str) -> Definition {
+        for node in self.program.syntax.descendants() {
+            if let Some(import) = ts::ImportDeclaration::cast(node) {
+                let imported: Option<SmolStr> = import.specifiers().find_map(|spec| match spec.kind() {
+                    ts::ModuleSpecifierKind::ImportDefaultSpecifier(spec) if spec.local().name() == name => {
+                        Some("default".into())
+                    }
+                    ts::ModuleSpecifierKind::ImportNamespaceSpecifier(spec) if spec.local().name() == name => {
+                        Some("*".into())
+                    }
+                    ts::ModuleSpecifierKind::ImportSpecifier(spec) if spec.local().name() == name => {
+                        Some(spec.imported().name().into())
+                    }
+                    _ => None,
+                });
+                let source = import.source().and_then(|lit| string_value(lit.into()));
+                if let (Some(name), Some(source)) = (imported, source) {
+                    let ast_id = self.source_map.ast_id(import).with_file_id(self.src_id);
+                    return Definition::Import { ast_id, source, name };
+                }
+            } else if let Some(decl) = ts::VariableDeclarator::cast(node) {
+                let is_match = decl.id()
+                    .and_then(ts::Identifier::downcast)
+                    .map(|id| id.name() == name)
+                    .unwrap_or(false);
+                if is_match {
+                    return decl.init()
+                        .filter(|init| ts::Identifier::downcast(*init).is_none())
+                        .map(|init| self.definition(init))
+                        .unwrap_or(Definition::Unknown);
+                }
+            }
+        }
+        Definition::Unknown
+    }
+}
+
 #[inline]
-fn try_register(registry: &mut VueRegistry, node: &SyntaxNode) -> Option<()> {
+fn try_register(registry: &mut VueRegistry, script: &ScriptContext, node: &SyntaxNode) -> Option<()> {
+    let aliases = &script.aliases;
+
+    // Match `Vue.prototype.$name = ...`
+    if let Some(assign) = ts::AssignmentExpression::cast(node) {
+        let target = match assign.left()?.kind() {
+            ts::PatternKind::MemberExpression(expr) => expr,
+            _ => return None,
+        };
+        if !is_vue_prototype(target.object()?, aliases) {
+            return None;
+        }
+        let registration = script.register(assign.into(), assign.right());
+        registry.instance_properties.insert(member_name(target)?, registration);
+        return Some(());
+    }
+
+    // Match `new Vue({ ... })`
+    if let Some(instance) = ts::NewExpression::cast(node) {
+        // N.B. the arguments are usually parsed as a call of the callee
+        let (callee, options) = match instance.callee().and_then(ts::CallExpression::downcast) {
+            Some(call) => (call.callee()?, call.arguments().next()),
+            None => (instance.callee()?, instance.arguments().next()),
+        };
+        if !is_vue(callee, aliases) {
+            return None;
+        }
+        registry.roots.push(script.register(instance.into(), options));
+        return Some(());
+    }
+
     let call = ts::CallExpression::cast(node)?;
     let callee = call.callee().and_then(ts::MemberExpression::downcast)?;
-    if callee.object().and_then(ts::Identifier::downcast)?.name() != "Vue" {
+    let object = callee.object()?;
+    let method = callee.property().and_then(ts::Identifier::downcast)?.name();
+    let mut args = call.arguments();
+
+    // Match `Object.defineProperty(Vue.prototype, '$name', ...)`
+    if method == "defineProperty" && ts::Identifier::downcast(object)?.name() == "Object" {
+        if !is_vue_prototype(args.next()?, aliases) {
+            return None;
+        }
+        let key = string_value(args.next()?)?;
+        registry.instance_properties.insert(key, script.register(call.into(), args.next()));
+        return Some(());
+    }
+
+    // Match `Vue.mixin({ ... })`
+    if !is_vue(object, aliases) {
         return None;
     }
+    if method == "mixin" {
+        registry.mixins.push(script.register(call.into(), args.next()));
+        return Some(());
+    }
 
-    let literal = call.arguments().next().and_then(ts::Literal::downcast)?;
-    let key = match literal.kind() {
-        ts::LiteralKind::String(tok) => {
-            let raw = tok.text().as_str();
-            unescape::unescape(&raw[1 .. raw.len() - 1])?
+    // Match `Vue.{component,directive,filter}('name', ...)`
+    let key = string_value(args.next()?)?;
+    let registration = script.register(call.into(), args.next());
+    match method {
+        "component" => registry.components.insert(key, registration),
+        "directive" => registry.directives.insert(key, registration),
+        "filter" => registry.filters.insert(key, registration),
+        _ => return None,
+    };
+    Some(())
+}
+
+/// Finds the options object of a component (e.g. `export default { ... }` or `export default Vue.extend({ ... })`).
+pub(crate) fn component_options(program: &ts::Program) -> Option<&ts::ObjectExpression> {
+    let maybe_default_export = program.syntax.children().find_map(ts::ExportDefaultDeclaration::cast);
+    let maybe_default_expr = maybe_default_export.and_then(|n| n.syntax.children().find_map(ts::Expression::cast));
+    maybe_default_expr.and_then(options_object)
+}
+
+/// Finds the options object of a component exported by name (e.g. `export const Base = { ... }`),
+/// or of the default export if `name` is `default`.
+pub(crate) fn exported_options<'a>(program: &'a ts::Program, name: &str) -> Option<&'a ts::ObjectExpression> {
+    if name == "default" {
+        return component_options(program);
+    }
+    let declared = |decl: &'a ts::VariableDeclaration, name: &str| {
+        decl.declarations()
+            .find(|decl| decl.id().and_then(ts::Identifier::downcast).map(|id| id.name() == name).unwrap_or(false))
+            .and_then(ts::VariableDeclarator::init)
+    };
+    let mut local = None;
+    for export in program.syntax.children().filter_map(ts::ExportNamedDeclaration::cast) {
+        if let Some(decl) = export.declaration().and_then(ts::VariableDeclaration::downcast) {
+            if let Some(init) = declared(decl, name) {
+                return options_object(init);
+            }
+        }
+        if let Some(spec) = export.specifiers().find(|spec| spec.exported().name() == name) {
+            local = Some(spec.local().name());
+        }
+    }
+
+    // N.B. e.g. `const Base = { ... }; export { Base };`
+    let local = local?;
+    program.syntax.children()
+        .filter_map(ts::VariableDeclaration::cast)
+        .find_map(|decl| declared(decl, local))
+        .and_then(options_object)
+}
+
+/// Finds the options object of a component definition (e.g. `{ ... }` or `Vue.extend({ ... })`).
+fn options_object(expr: &ts::Expression) -> Option<&ts::ObjectExpression> {
+    match expr.kind() {
+        ts::ExpressionKind::CallExpression(call) => {
+            let maybe_vue_extend = call.syntax.first_child().and_then(ts::MemberExpression::cast)?;
+            let maybe_vue = maybe_vue_extend.syntax.first_token()?;
+            let maybe_extend = maybe_vue_extend.syntax.last_token()?;
+            if maybe_vue.text() != "Vue" || maybe_extend.text() != "extend" {
+                return None;
+            }
+            let start_args = call.syntax.children_with_tokens().find(|c| c.kind() == L_PAREN)?;
+            start_args.next_sibling_or_token()
+                .and_then(|el| match el {
+                    SyntaxElement::Node(node) => Some(node),
+                    SyntaxElement::Token(_) => None,
+                })
+                .and_then(ts::ObjectExpression::cast)
         }
+        ts::ExpressionKind::ObjectExpression(object) => Some(object),
+        _ => None,
+    }
+}
+
+/// Finds the value of a (non-computed) property of an object.
+pub(crate) fn get_object_property<'a>(obj: &'a ts::ObjectExpression, key: &str) -> Option<&'a ts::Expression> {
+    obj.properties()
+        .find(|prop| infer_property_name(prop).as_ref().map(|x| x.as_str()) == Some(key))
+        .and_then(|prop| prop.value())
+}
+
+/// Finds the module loaded by an async component, e.g. `() => import('./Foo.vue')`
+/// or `() => ({ component: import('./Foo.vue'), ... })`.
+fn async_import_source(expr: &ts::Expression) -> Option<SmolStr> {
+    let result = match expr.kind() {
+        ts::ExpressionKind::ArrowFunctionExpression(_) |
+        ts::ExpressionKind::FunctionExpression(_) => returned_expression(expr)?,
         _ => return None,
     };
-    match callee.property().and_then(ts::Identifier::downcast)?.name() {
-        "component" => registry.components.insert(key.into()),
-        "filter" => registry.filters.insert(key.into()),
-        "mixin" => registry.mixins.insert(key.into()),
+    let import = match result.kind() {
+        ts::ExpressionKind::CallExpression(call) => call,
+        ts::ExpressionKind::ObjectExpression(obj) => obj.properties()
+            .find(|prop| infer_property_name(prop).as_ref().map(SmolStr::as_str) == Some("component"))
+            .and_then(|prop| prop.value())
+            .and_then(ts::CallExpression::downcast)?,
         _ => return None,
     };
-    Some(())
+    if !import.is_import() {
+        return None;
+    }
+    string_value(import.arguments().next()?)
+}
+
+/// Finds the value returned by a function (e.g. `() => value` or `function () { return value }`).
+pub(crate) fn returned_expression(func: &ts::Expression) -> Option<&ts::Expression> {
+    let block = match func.kind() {
+        ts::ExpressionKind::ArrowFunctionExpression(func) => match func.body()? {
+            ts::ArrowFunctionBody::Expression(expr) => return Some(expr),
+            ts::ArrowFunctionBody::FunctionBody(block) => block,
+        },
+        ts::ExpressionKind::FunctionExpression(func) => func.body()?,
+        _ => return None,
+    };
+    block.body().last().and_then(|stmt| ts::ReturnStatement::cast(&stmt.syntax))?.argument()
+}
+
+/// Finds each function which looks like a plugin (e.g. `install(Vue) { ... }` or a function passed
+/// to `Vue.use`) and returns the range of the function with the name of its `Vue` parameter.
+fn find_plugin_aliases(root: &SyntaxNode) -> Vec<(TextRange, SmolStr)> {
+    // Find plugins that are installed by name (e.g. `Vue.use(MyPlugin)`)
+    let mut installed = FxHashSet::default();
+    for node in root.descendants() {
+        let plugin = ts::CallExpression::cast(node)
+            .filter(|call| is_vue_use(call))
+            .and_then(|call| call.arguments().next())
+            .and_then(ts::Identifier::downcast);
+        if let Some(plugin) = plugin {
+            installed.insert(plugin.name());
+        }
+    }
+
+    let mut aliases = Vec::new();
+    for node in root.descendants() {
+        let func = match ts::Function::cast(node) {
+            Some(func) => func,
+            None => continue,
+        };
+        let (name, param) = match func.kind() {
+            ts::FunctionKind::FunctionDeclaration(func) => (Some(SmolStr::from(func.id().name())), func.params().next()),
+            ts::FunctionKind::FunctionExpression(func) => (func.id().map(|id| id.name().into()), func.params().next()),
+            ts::FunctionKind::ArrowFunctionExpression(func) => (None, func.params().next()),
+        };
+        let name = name.or_else(|| {
+            let parent = node.parent()?;
+            if let Some(decl) = ts::VariableDeclarator::cast(parent) {
+                decl.id().and_then(ts::Identifier::downcast).map(|id| id.name().into())
+            } else if let Some(prop) = ts::Property::cast(parent) {
+                infer_property_name(prop)
+            } else if let Some(assign) = ts::AssignmentExpression::cast(parent) {
+                match assign.left()?.kind() {
+                    ts::PatternKind::MemberExpression(expr) => member_name(expr),
+                    _ => None,
+                }
+            } else {
+                None
+            }
+        });
+        let is_plugin = match &name {
+            Some(name) => name.as_str() == "install" || installed.contains(name.as_str()),
+            None => node.parent().and_then(ts::CallExpression::cast).map(is_vue_use).unwrap_or(false),
+        };
+        if !is_plugin {
+            continue;
+        }
+        let alias = param.and_then(|param| match param.kind() {
+            ts::PatternKind::Identifier(ident) => Some(ident.name().into()),
+            _ => None,
+        });
+        if let Some(alias) = alias {
+            aliases.push((node.range(), alias));
+        }
+    }
+    aliases
+}
+
+fn is_vue(expr: &ts::Expression, aliases: &[(TextRange, SmolStr)]) -> bool {
+    let name = match ts::Identifier::downcast(expr) {
+        Some(ident) => ident.name(),
+        None => return false,
+    };
+    let range = expr.syntax.range();
+    name == "Vue" || aliases.iter().any(|(scope, alias)| alias.as_str() == name && range.is_subrange(scope))
+}
+
+fn is_vue_prototype(expr: &ts::Expression, aliases: &[(TextRange, SmolStr)]) -> bool {
+    match ts::MemberExpression::downcast(expr) {
+        Some(member) => {
+            member.object().map(|obj| is_vue(obj, aliases)).unwrap_or(false) &&
+                member_name(member).as_ref().map(SmolStr::as_str) == Some("prototype")
+        }
+        None => false,
+    }
+}
+
+fn is_vue_use(call: &ts::CallExpression) -> bool {
+    call.callee()
+        .and_then(ts::MemberExpression::downcast)
+        .filter(|callee| callee.object().map(|obj| is_vue(obj, &[])).unwrap_or(false))
+        .and_then(member_name)
+        .map(|name| name.as_str() == "use")
+        .unwrap_or(false)
+}
+
+/// Returns the name of the property accessed by `object.name` or `object['name']`.
+fn member_name(expr: &ts::MemberExpression) -> Option<SmolStr> {
+    let property = expr.property()?;
+    if expr.computed() {
+        string_value(property)
+    } else {
+        ts::Identifier::downcast(property).map(|ident| ident.name().into())
+    }
+}
+
+pub(crate) fn string_value(expr: &ts::Expression) -> Option<SmolStr> {
+    match ts::Literal::downcast(expr)?.kind() {
+        ts::LiteralKind::String(tok) => {
+            let raw = tok.text().as_str();
+            unescape::unescape(&raw[1 .. raw.len() - 1]).map(SmolStr::from)
+        }
+        _ => None,
+    }
 }
 
 /// A registry of components registered with `Vue.component`, `Vue.filter`, etc...
 #[derive(Default, Eq, PartialEq)]
 pub struct VueRegistry {
-    pub components: FxHashSet<SmolStr>, // TODO: Switch to `FxHashMap<SmolStr, AstId>`
-    pub filters: FxHashSet<SmolStr>,
-    pub mixins: FxHashSet<SmolStr>, // TODO: Switch to `FxHashMap<SmolStr, AstId>`
+    pub components: FxHashMap<SmolStr, Registration>,
+    pub directives: FxHashMap<SmolStr, Registration>,
+    pub filters: FxHashMap<SmolStr, Registration>,
+    /// Global mixins are anonymous, so they are kept in the order they were registered.
+    pub mixins: Vec<Registration>,
+    /// Properties added to every component by plugins (e.g. `Vue.prototype.$http = ...`).
+    pub instance_properties: FxHashMap<SmolStr, Registration>,
+    /// Root instances created with `new Vue({ ... })`.
+    pub roots: Vec<Registration>,
 }
 
 impl fmt::Debug for VueRegistry {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         fmt.debug_struct("VueRegistry")
             .field("components", &self.components.len())
+            .field("directives", &self.directives.len())
             .field("filters", &self.filters.len())
             .field("mixins", &self.mixins.len())
+            .field("instance_properties", &self.instance_properties.len())
+            .field("roots", &self.roots.len())
             .finish()
     }
 }
 
 impl VueRegistry {
     pub fn extend(&mut self, other: &VueRegistry) {
-        self.components.extend(other.components.iter().cloned());
-        self.filters.extend(other.filters.iter().cloned());
+        self.components.extend(other.components.iter().map(|(k, v)| (k.clone(), v.clone())));
+        self.directives.extend(other.directives.iter().map(|(k, v)| (k.clone(), v.clone())));
+        self.filters.extend(other.filters.iter().map(|(k, v)| (k.clone(), v.clone())));
         self.mixins.extend(other.mixins.iter().cloned());
+        self.instance_properties.extend(other.instance_properties.iter().map(|(k, v)| (k.clone(), v.clone())));
+        self.roots.extend(other.roots.iter().cloned());
+    }
+}
+
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct ComponentGraph {
+    /// The components which use each component in their template.
+    pub parents: FxHashMap<FileId, Vec<FileId>>,
+    /// Components rendered by a root instance, with the keys provided by that instance.
+    pub roots: FxHashMap<FileId, Provides>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Provides {
+    pub keys: Vec<SmolStr>,
+    /// Whether every provided key could be determined.
+    pub complete: bool,
+}
+
+impl Default for Provides {
+    fn default() -> Provides {
+        Provides { keys: Vec::new(), complete: true }
+    }
+}
+
+impl Provides {
+    /// Returns `None` if the key may or may not be provided.
+    pub fn provides(&self, key: &str) -> Option<bool> {
+        if self.keys.iter().any(|k| k == key) {
+            Some(true)
+        } else if self.complete {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+/// Where a component, filter, etc. was registered with the global `Vue` instance.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Registration {
+    /// The file which contains the registration.
+    pub file_id: FileId,
+    /// The expression which registered the definition (e.g. `Vue.component('my-component', MyComponent)`).
+    ///
+    /// N.B. the registering script's `SourceId` is available from `ast_id.file_id()`.
+    pub ast_id: AstId<ts::Expression>,
+    pub definition: Definition,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Definition {
+    /// An options object (or other expression) in the registering script.
+    Expression(AstId<ts::Expression>),
+    /// A definition imported from another module, where `name` is the imported
+    /// binding (either `default`, `*` or the name of a named export).
+    Import {
+        ast_id: AstId<ts::ImportDeclaration>,
+        source: SmolStr,
+        name: SmolStr,
+    },
+    /// A component which is loaded lazily by a factory (e.g. `() => import('./Foo.vue')`).
+    AsyncImport {
+        ast_id: AstId<ts::Expression>,
+        source: SmolStr,
+    },
+    /// The definition couldn't be found statically.
+    Unknown,
+}
+
+impl Registration {
+    /// Resolves the file which provides an imported (or lazily imported) definition.
+    pub fn resolve_file(&self, db: &impl ModuleDatabase) -> Option<FileId> {
+        self.definition.resolve_file(db, self.file_id)
+    }
+}
+
+impl Definition {
+    /// Resolves the file which provides an imported (or lazily imported) definition,
+    /// where `file_id` is the file containing the definition's import.
+    pub fn resolve_file(&self, db: &impl ModuleDatabase, file_id: FileId) -> Option<FileId> {
+        match self {
+            Definition::Import { source, .. } | Definition::AsyncImport { source, .. } => {
+                db.resolve_import(file_id, source.clone())
+            }
+            Definition::Expression(_) | Definition::Unknown => None,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "runtime"))]
+mod tests {
+    use super::*;
+    use crate::runtime::HostDatabase;
+
+    fn names(map: &FxHashMap<SmolStr, Registration>) -> Vec<&str> {
+        let mut names = map.keys().map(SmolStr::as_str).collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_find_plugin_aliases() {
+        let (program, _) = ts::Program::parse(r#"
+            const plugin = { install(V, options) {} };
+            const MyPlugin = function (P) {};
+            Vue.use(MyPlugin);
+            Vue.use((A) => {});
+            function helper(H) {}
+            helper((B) => {});
+        "#);
+        let aliases = find_plugin_aliases(&program.syntax);
+        let aliases = aliases.iter().map(|(_, alias)| alias.as_str()).collect::<Vec<_>>();
+        assert_eq!(aliases, vec!["V", "P", "A"]);
+    }
+
+    #[test]
+    fn test_script_registry() {
+        let (db, files) = HostDatabase::with_files(&[
+            ("src/main.js", r#"
+                import Vue from 'vue';
+                Vue.prototype.$http = {};
+                Object.defineProperty(Vue.prototype, '$store', { get() { return null; } });
+                Vue.component('MyButton', {});
+                Vue.directive('focus', {});
+                Vue.filter('currency', (value) => value);
+                Vue.mixin({});
+                Vue.use((V) => {
+                    V.prototype.$log = console.log;
+                    V.directive('tooltip', {});
+                });
+                function unrelated(V) {
+                    V.prototype.$unrelated = null;
+                    V.component('NotRegistered', {});
+                }
+                new Vue({ el: '#app' });
+            "#),
+            ("src/plugin.js", r#"
+                export default {
+                    install(Vue) {
+                        Vue.prototype['$auth'] = {};
+                    }
+                };
+            "#),
+        ]);
+        let registry = db.script_registry(files[0]);
+        assert_eq!(names(&registry.components), vec!["MyButton"]);
+        assert_eq!(names(&registry.directives), vec!["focus", "tooltip"]);
+        assert_eq!(names(&registry.filters), vec!["currency"]);
+        assert_eq!(names(&registry.instance_properties), vec!["$http", "$log", "$store"]);
+        assert_eq!(registry.mixins.len(), 1);
+        assert_eq!(registry.roots.len(), 1);
+
+        let global = db.global_registry(SourceRootId(0));
+        assert_eq!(names(&global.instance_properties), vec!["$auth", "$http", "$log", "$store"]);
     }
 }
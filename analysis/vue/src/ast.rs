@@ -1,6 +1,8 @@
 use code_analysis::{AstId, AstIdMap, SourceDatabase, Source, SourceId, impl_intern_key, impl_source_key};
-use code_grammar::{AstNode, TreeArc};
+use code_grammar::{AstNode, Location, SyntaxElement, SyntaxError, SyntaxNode, SyntaxToken, TextRange, TextUnit, TreeArc, WalkEvent};
+use javascript_grammar::ast as js;
 use vue_grammar::ast as vue;
+use vue_grammar::syntax_kind::*;
 use std::sync::Arc;
 
 #[salsa::query_group(AstDatabaseStorage)]
@@ -12,6 +14,14 @@ pub trait AstDatabase: SourceDatabase {
     fn component_script(&self, file_id: SourceId) -> Option<(SourceId, &'static str)>;
     #[salsa::interned]
     fn intern_component_script(&self, script: ComponentScript) -> ComponentScriptId;
+
+    /// Finds the expressions, `v-on` handlers and scope patterns embedded in the component's template.
+    fn template_expressions(&self, file_id: SourceId) -> Arc<Vec<TemplateExpressionId>>;
+    fn template_expression_source(&self, expr_id: TemplateExpressionId) -> SourceId;
+    /// Parses an embedded template expression.
+    fn template_code(&self, expr_id: TemplateExpressionId) -> TemplateCode;
+    #[salsa::interned]
+    fn intern_template_expression(&self, expr: TemplateExpression) -> TemplateExpressionId;
 }
 
 pub fn vue_ast(db: &impl AstDatabase, file_id: SourceId) -> TreeArc<vue::Component> {
@@ -41,8 +51,9 @@ pub fn component_script(db: &impl AstDatabase, file_id: SourceId) -> Option<(Sou
         ast_id: source_map.ast_id(script).with_file_id(file_id),
         lang: "js", // TODO: detect source language (e.g. handle `lang="ts"` attribute)
     });
-    let content = script.script()?.source()?.text().to_string();
-    let source = Source::from_source_key(script_id, content.into());
+    let content = script.script()?.source()?;
+    let source = Source::from_source_key(script_id, content.text().to_string().into())
+        .with_origin(file_id, content.range());
     let source_id = db.source_id(source);
     Some((source_id, "js"))
 }
@@ -59,3 +70,304 @@ pub struct ComponentScript {
     /// The `lang` attribute of the script
     pub lang: &'static str,
 }
+
+pub fn template_expressions(db: &impl AstDatabase, file_id: SourceId) -> Arc<Vec<TemplateExpressionId>> {
+    let component = db.vue_ast(file_id);
+    let text = db.source_text(file_id);
+    let template = match component.template() {
+        Some(template) => template,
+        None => return Default::default(),
+    };
+    let expressions = collect_template_expressions(template)
+        .into_iter()
+        .map(|(kind, range, scope)| {
+            // N.B. whitespace around an expression isn't part of its source
+            let raw = &text[range];
+            let trim_offset = TextUnit::of_str(&raw[.. raw.len() - raw.trim_start().len()]);
+            let range = TextRange::offset_len(range.start() + trim_offset, TextUnit::of_str(raw.trim()));
+            db.intern_template_expression(TemplateExpression { file_id, kind, range, scope })
+        })
+        .collect();
+    Arc::new(expressions)
+}
+
+pub fn template_expression_source(db: &impl AstDatabase, expr_id: TemplateExpressionId) -> SourceId {
+    let expr = db.lookup_intern_template_expression(expr_id);
+    let content = db.source_text(expr.file_id)[expr.range].to_string();
+    let source = Source::from_source_key(expr_id, content.into()).with_origin(expr.file_id, expr.range);
+    db.source_id(source)
+}
+
+pub fn template_code(db: &impl AstDatabase, expr_id: TemplateExpressionId) -> TemplateCode {
+    let expr = db.lookup_intern_template_expression(expr_id);
+    let text = db.source_text(db.template_expression_source(expr_id));
+    TemplateCode::parse(&text, expr.kind)
+}
+
+/// Finds the range and kind of each expression in a template, with the range of the element it belongs to.
+fn collect_template_expressions(template: &vue::Template) -> Vec<(TemplateExpressionKind, TextRange, TextRange)> {
+    let mut expressions = Vec::new();
+    for visit in template.syntax.preorder_with_tokens() {
+        let syn_elem = match visit {
+            WalkEvent::Enter(syntax) => syntax,
+            _ => continue,
+        };
+        let node = match syn_elem {
+            SyntaxElement::Node(node) => node,
+            SyntaxElement::Token(token) => {
+                if token.kind() == MUSTACHE {
+                    let range = token.range();
+                    let start = range.start() + TextUnit::of_str("{{");
+                    let end = range.end() - TextUnit::of_str("}}");
+                    expressions.push((TemplateExpressionKind::Binding, TextRange::from_to(start, end), token.parent().range()));
+                }
+                continue;
+            }
+        };
+        let scope = match node.parent() {
+            Some(element) => element.range(),
+            None => continue,
+        };
+
+        // N.B. `slot-scope` isn't a directive, but declares its scope like `v-slot`
+        let (name, value, range) = match vue::Directive::cast(node) {
+            Some(directive) => {
+                // A dynamic argument (e.g. `:[key]`) is an expression
+                if let Some(key) = directive.argument().filter(|key| key.is_dynamic()) {
+                    if !key.range().is_empty() {
+                        expressions.push((TemplateExpressionKind::Binding, key.range(), scope));
+                    }
+                }
+                match (directive.value(), directive.value_range()) {
+                    (Some(value), Some(range)) => (directive.name(), value, range),
+                    _ => continue,
+                }
+            }
+            None if node.kind() == ATTRIBUTE => match (node.first_token(), attribute_value(node)) {
+                (Some(name), Some(value)) if name.text() == "slot-scope" && value.kind() == QUOTED => {
+                    let text = value.text().as_str();
+                    let range = value.range();
+                    let start = range.start() + TextUnit::of_char('"');
+                    let end = range.end() - TextUnit::of_char('"');
+                    ("slot-scope", &text[1 .. text.len() - 1], TextRange::from_to(start, end))
+                }
+                _ => continue,
+            },
+            None => continue,
+        };
+        match name {
+            "slot" | "slot-scope" => expressions.push((TemplateExpressionKind::Pattern, range, scope)),
+            "for" => {
+                // Each alias is a pattern (e.g. `item` and `index` in `(item, index) in items`)
+                let (aliases, source) = match value.find(" in ").or_else(|| value.find(" of ")) {
+                    Some(offset) => (&value[.. offset], offset + " in ".len()),
+                    None => continue,
+                };
+                // N.B. only split at commas which aren't inside a destructuring pattern (e.g. `({ id, name }, index)`)
+                let trimmed = aliases.trim();
+                let mut offset = aliases.len() - aliases.trim_start().len();
+                let aliases = if trimmed.starts_with('(') && trimmed.ends_with(')') {
+                    offset += 1; // eat `(`
+                    &trimmed[1 .. trimmed.len() - 1]
+                } else {
+                    trimmed
+                };
+                let mut depth = 0;
+                let mut alias_start = 0;
+                for (idx, c) in aliases.char_indices().chain(std::iter::once((aliases.len(), ','))) {
+                    match c {
+                        '{' | '[' | '(' => depth += 1,
+                        '}' | ']' | ')' => depth -= 1,
+                        ',' if depth == 0 => {
+                            let alias = &aliases[alias_start .. idx];
+                            if !alias.trim().is_empty() {
+                                let start = range.start() + TextUnit::from_usize(offset + alias_start);
+                                let alias_range = TextRange::offset_len(start, TextUnit::of_str(alias));
+                                expressions.push((TemplateExpressionKind::Pattern, alias_range, scope));
+                            }
+                            alias_start = idx + 1; // eat `,`
+                        }
+                        _ => (),
+                    }
+                }
+
+                // The rest is an expression
+                let start = range.start() + TextUnit::from_usize(source);
+                expressions.push((TemplateExpressionKind::Binding, TextRange::from_to(start, range.end()), scope));
+            }
+            "on" if node.kind() == ATTRIBUTE_LISTENER => expressions.push((TemplateExpressionKind::Handler, range, scope)),
+            // N.B. includes `v-show`, `v-text`, `v-html` and the values of custom directives
+            _ => expressions.push((TemplateExpressionKind::Binding, range, scope)),
+        }
+    }
+    expressions
+}
+
+/// Finds the value of a static attribute, i.e. the token after `=`.
+pub(crate) fn attribute_value(attr: &SyntaxNode) -> Option<SyntaxToken> {
+    attr.children_with_tokens()
+        .skip_while(|syn| syn.kind() != EQ)
+        .skip(1) // eat `EQ`
+        .skip_while(|syn| syn.kind() == WS)
+        .next()
+        .and_then(|syn| match syn {
+            SyntaxElement::Token(token) => Some(token),
+            _ => None,
+        })
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct TemplateExpressionId(salsa::InternId);
+impl_intern_key!(TemplateExpressionId);
+impl_source_key!(TemplateExpressionId);
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TemplateExpression {
+    /// The source of the component containing the template.
+    pub file_id: SourceId,
+    pub kind: TemplateExpressionKind,
+    /// The range of the expression in the component's source, without any surrounding whitespace.
+    pub range: TextRange,
+    /// The range of the element containing the expression, which is the scope of any names declared by a pattern.
+    pub scope: TextRange,
+}
+
+/// Where an expression appears in a template, which determines how it's parsed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum TemplateExpressionKind {
+    /// A single expression (e.g. `:title="..."` or `{{ ... }}`).
+    Binding,
+    /// The handler of an event listener, which may be a list of statements (e.g. `@click="a = 1; b++"`).
+    Handler,
+    /// A pattern which declares names in the scope of an element (e.g. `item` in `v-for="item in items"`).
+    Pattern,
+}
+
+/// The parsed code of an expression in a template.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TemplateCode {
+    Expression(TreeArc<js::Expression>),
+    Statements(TreeArc<js::Program>),
+    Pattern(TreeArc<js::Pattern>),
+}
+
+impl TemplateCode {
+    fn parse(text: &str, kind: TemplateExpressionKind) -> TemplateCode {
+        if kind == TemplateExpressionKind::Pattern {
+            let (pattern, _) = js::Pattern::parse(text);
+            return TemplateCode::Pattern(pattern);
+        }
+        let (expr, remainder) = js::Expression::parse(text);
+        let expr_ok = expr.errors().is_empty();
+        // N.B. empty code (e.g. `v-if=""`) is reported as a missing expression
+        if (expr_ok && remainder.trim().is_empty()) || text.trim().is_empty() {
+            return TemplateCode::Expression(expr);
+        }
+        let (program, _) = js::Program::parse(text);
+        if kind == TemplateExpressionKind::Binding && !expr_ok && !program.errors().is_empty() {
+            TemplateCode::Expression(expr)
+        } else {
+            TemplateCode::Statements(program)
+        }
+    }
+
+    /// Returns the syntax errors in the code, given where the code appears in the template.
+    pub fn errors(&self, kind: TemplateExpressionKind) -> Vec<SyntaxError> {
+        match self {
+            TemplateCode::Expression(expr) => expr.errors(),
+            TemplateCode::Statements(program) => {
+                let errors = program.errors();
+                if errors.is_empty() && kind == TemplateExpressionKind::Binding {
+                    let location = Location::Offset(TextUnit::default());
+                    vec![SyntaxError::new("statements are not allowed here, only `v-on` handlers may contain statements", location)]
+                } else {
+                    errors
+                }
+            }
+            TemplateCode::Pattern(pattern) => pattern.errors(),
+        }
+    }
+
+    pub fn syntax(&self) -> &SyntaxNode {
+        match self {
+            TemplateCode::Expression(expr) => &expr.syntax,
+            TemplateCode::Statements(program) => &program.syntax,
+            TemplateCode::Pattern(pattern) => &pattern.syntax,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses template code, returning how it was parsed with its syntax errors.
+    fn parse(text: &str, kind: TemplateExpressionKind) -> (&'static str, Vec<String>) {
+        let code = TemplateCode::parse(text, kind);
+        let errors = code.errors(kind).into_iter().map(|err| err.message.to_string()).collect();
+        match code {
+            TemplateCode::Expression(_) => ("expression", errors),
+            TemplateCode::Statements(_) => ("statements", errors),
+            TemplateCode::Pattern(_) => ("pattern", errors),
+        }
+    }
+
+    #[test]
+    fn test_parse_handlers() {
+        use TemplateExpressionKind::Handler;
+        assert_eq!(parse("save", Handler), ("expression", vec![]));
+        assert_eq!(parse("save($event, ...args)", Handler), ("expression", vec![]));
+        assert_eq!(parse("if (ok) go()", Handler), ("statements", vec![]));
+        assert_eq!(parse("count++; save()", Handler), ("statements", vec![]));
+        assert_eq!(parse("$event => save($event)", Handler), ("expression", vec![]));
+        assert_eq!(parse("function (event) { save(event) }", Handler), ("expression", vec![]));
+        assert_eq!(parse("", Handler).0, "expression");
+    }
+
+    #[test]
+    fn test_parse_bindings() {
+        use TemplateExpressionKind::Binding;
+        assert_eq!(parse("format(date, ...args)", Binding), ("expression", vec![]));
+        assert_eq!(parse("if (ok) go()", Binding).0, "statements");
+        assert_eq!(parse("a; b", Binding), (
+            "statements",
+            vec!["statements are not allowed here, only `v-on` handlers may contain statements".to_string()],
+        ));
+        assert!(!parse("a +", Binding).1.is_empty());
+    }
+
+    #[cfg(feature = "runtime")]
+    #[test]
+    fn test_original_ranges() {
+        use crate::runtime::HostDatabase;
+        use code_analysis::{original_range, source_range};
+
+        let component = "<template>\n  <p :title=\" label \">{{ count }}</p>\n</template>\n<script>\nexport default { props: ['label'] };\n</script>";
+        let (db, file_ids) = HostDatabase::with_files(&[("src/App.vue", component)]);
+        let src_id = db.file_source(file_ids[0]);
+        let line_index = db.source_line_index(src_id);
+        let line_col = |source_id: SourceId, range: TextRange| {
+            let original = original_range(&db, source_id, range).unwrap();
+            assert_eq!(original.file_id, file_ids[0]);
+            assert_eq!(source_range(&db, source_id, original.range), Some(range));
+            let pos = line_index.line_col(original.range.start());
+            (pos.line + 1, pos.col_utf16 + 1)
+        };
+
+        // The expressions in the template (without their surrounding whitespace)
+        let expressions: Vec<_> = db.template_expressions(src_id).iter()
+            .map(|&expr_id| db.template_expression_source(expr_id))
+            .collect();
+        assert_eq!(expressions.iter().map(|&id| db.source_text(id).to_string()).collect::<Vec<_>>(), vec!["label", "count"]);
+        assert_eq!(line_col(expressions[0], TextRange::offset_len(0.into(), 5.into())), (2, 15));
+        assert_eq!(line_col(expressions[1], TextRange::offset_len(0.into(), 5.into())), (2, 26));
+
+        // The component's script
+        let (script_id, _) = db.component_script(src_id).unwrap();
+        let offset = db.source_text(script_id).find("props").unwrap();
+        assert_eq!(line_col(script_id, TextRange::offset_len(TextUnit::from_usize(offset), 5.into())), (5, 18));
+
+        // A range outside of the script doesn't map into it
+        assert_eq!(source_range(&db, script_id, TextRange::offset_len(0.into(), 5.into())), None);
+    }
+}
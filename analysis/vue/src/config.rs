@@ -1,4 +1,5 @@
 use code_analysis::SourceRootId;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 #[salsa::query_group(ConfigDatabaseStorage)]
@@ -12,7 +13,9 @@ pub trait ConfigDatabase {
 #[serde(deny_unknown_fields)]
 pub struct Config {
     #[serde(default)]
-    pub(crate) global: ConfigGlobals
+    pub(crate) global: ConfigGlobals,
+    #[serde(default)]
+    pub(crate) resolve: ConfigResolve,
 }
 
 #[derive(Debug, Default)]
@@ -22,5 +25,24 @@ pub(crate) struct ConfigGlobals {
     #[serde(default)]
     pub(crate) components: Vec<String>,
     #[serde(default)]
+    pub(crate) directives: Vec<String>,
+    #[serde(default)]
     pub(crate) filters: Vec<String>,
-}
\ No newline at end of file
+    /// Custom key modifier aliases, like `Vue.config.keyCodes` (e.g. `f1` or `media-play-pause`).
+    #[serde(default)]
+    pub(crate) key_codes: Vec<String>,
+    /// Component options used by plugins (e.g. `i18n` or `head`).
+    #[serde(default)]
+    pub(crate) options: Vec<String>,
+    /// Instance properties added by plugins which aren't part of the project (e.g. `$store`).
+    #[serde(default)]
+    pub(crate) properties: Vec<String>,
+}
+#[derive(Debug, Default)]
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ConfigResolve {
+    /// Module aliases relative to the project root, like webpack's `resolve.alias` (e.g. `"@" = "src"`).
+    #[serde(default)]
+    pub(crate) alias: BTreeMap<String, String>,
+}
@@ -58,7 +58,7 @@ mod tests {
         assert_diff!(
             syn.trim(),
             r#"
-PROGRAM@[0; 17)
+PROGRAM@[0; 33)
   FUNCTION_DECLARATION@[0; 17)
     FUNCTION_KW@[0; 8)  "function"
     WHITESPACE@[8; 9)
@@ -70,6 +70,8 @@ PROGRAM@[0; 17)
     BLOCK_STATEMENT@[15; 17)
       L_CURLY@[15; 16)  "{"
       R_CURLY@[16; 17)  "}"
+  WHITESPACE@[17; 18)
+  COMMENT@[18; 33)  "/* a comment */"
 "#
             .trim()
         );
@@ -93,7 +95,7 @@ DOCUMENT@[0; 46)
       ATTRIBUTE@[15; 32)
         IDENTIFIER@[15; 18)  "alt"
         EQ@[18; 19)  "="
-        QUOTED@[19; 32)  "\'Hello World\'"
+        QUOTED@[19; 32)  "'Hello World'"
       WHITESPACE@[32; 33)
       SLASH_R_ANGLE@[33; 35)  "/>"
     L_ANGLE_SLASH@[35; 37)  "</"
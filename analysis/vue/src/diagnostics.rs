@@ -1,7 +1,24 @@
+mod a11y;
+mod calls;
+mod content;
+mod imports;
+mod inject;
+mod members;
+mod model;
+mod modifiers;
+mod options;
+mod security;
+mod suppression;
+mod watch;
+
 use crate::VueDatabase;
-use code_analysis::{FileId, SourceId};
-use code_grammar::{AstNode, SyntaxElement, SyntaxError, TextUnit, TextRange, WalkEvent};
-use javascript_grammar::ast as js;
+use crate::ast::{TemplateCode, TemplateExpressionKind};
+use crate::app::{
+    component_options, element_tag_names, exported_options, find_definition, get_object_property, local_components,
+    script_source, to_kebab_case, Definition, Registration,
+};
+use code_analysis::{original_position, original_range, FileId, SourceId};
+use code_grammar::{AstNode, SmolStr, SyntaxElement, SyntaxError, SyntaxNode, SyntaxNodeExt, TextUnit, TextRange, WalkEvent};
 use typescript_analysis::ty::{infer_property_name, infer_expression_type, InterfaceTy, PropertyDef, Ty, TypeOf};
 use typescript_grammar::ast as ts;
 use typescript_grammar::syntax_kind::*;
@@ -10,104 +27,320 @@ use vue_grammar::syntax_kind::*;
 use rustc_hash::FxHashSet;
 
 pub(crate) fn check(db: &impl VueDatabase, file_id: FileId) -> Vec<String> {
+    let path = db.file_relative_path(file_id);
+    let src_id = db.file_source(file_id);
     let mut results = Vec::new();
+    let complete = check_file(db, file_id, &mut results);
+
+    // Remove diagnostics covered by `vue-analyzer-disable` comments
+    let mut suppressions = suppression::collect(db, file_id);
+    results.retain(|diag| !suppressions.suppress(diag));
+    // N.B. a suppression might only look unused because the checks it was written for were skipped
+    if complete {
+        results.extend(suppressions.unused());
+    }
+    results.iter().map(|diag| diag.render(db, src_id, path.as_str())).collect()
+}
+
+/// A message produced while checking a file.
+#[derive(Debug)]
+pub(crate) struct Diagnostic {
+    pub severity: Severity,
+    /// The rule (or category) of the diagnostic, used to suppress it.
+    pub rule: &'static str,
+    /// The offset of the diagnostic in the checked file.
+    pub pos: Option<TextUnit>,
+    pub message: String,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Severity {
+    Error,
+    Warning,
+}
+
+impl Diagnostic {
+    pub(crate) fn error(rule: &'static str, pos: TextUnit, message: impl Into<String>) -> Diagnostic {
+        Diagnostic { severity: Severity::Error, rule, pos: Some(pos), message: message.into() }
+    }
+
+    pub(crate) fn warn(rule: &'static str, pos: TextUnit, message: impl Into<String>) -> Diagnostic {
+        Diagnostic { severity: Severity::Warning, rule, pos: Some(pos), message: message.into() }
+    }
+
+    fn render(&self, db: &impl VueDatabase, src_id: SourceId, filename: &str) -> String {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warn",
+        };
+        match self.pos {
+            Some(pos) => format!("{}({}): [{}] {}", severity, self.rule, error_at(db, src_id, filename, pos), self.message),
+            None => format!("{}({}): {}", severity, self.rule, self.message),
+        }
+    }
+}
+
+/// Returns whether every check ran, or false if some checks were skipped (e.g. because of syntax errors).
+fn check_file(db: &impl VueDatabase, file_id: FileId, results: &mut Vec<Diagnostic>) -> bool {
     let path = db.file_relative_path(file_id);
     let src_id = db.file_source(file_id);
     match path.extension() {
         Some("js") | Some("ts") => {
             let module = db.typescript_ast(src_id);
-            syntax_errors(&mut results, db, src_id, path.as_str(), TextUnit::default(), module.errors());
-            return results;
+            let errors = module.errors();
+            if errors.is_empty() {
+                imports::check_imports(db, file_id, &module, TextUnit::default(), results);
+                security::check_script(&module, TextUnit::default(), results);
+                return true;
+            }
+            syntax_errors(db, results, src_id, errors);
+            return false;
+        }
+        Some("htm") | Some("html") => {
+            let document = db.html_ast(src_id);
+            syntax_errors(db, results, src_id, document.errors());
+            a11y::check_a11y(&document.syntax, false, results);
+            security::check_template(&document.syntax, results);
+            content::check_document(&document.syntax, results);
+            return true;
         }
         Some("vue") => (),
         _ => {
-            results.push("error(usage): expected file extension to be 'vue'".into());
-            return results;
+            results.push(Diagnostic {
+                severity: Severity::Error,
+                rule: "usage",
+                pos: None,
+                message: "expected file extension to be 'vue'".into(),
+            });
+            return false;
         }
     }
 
     // Parse the vue component
     let component = db.vue_ast(src_id);
-    syntax_errors(&mut results, db, src_id, path.as_str(), TextUnit::default(), component.errors());
+    syntax_errors(db, results, src_id, component.errors());
+    let root_id = db.file_source_root(file_id);
+    let config = db.vue_config(root_id);
+    let global = db.global_registry(root_id);
+    let local_components = script_components(db, file_id, src_id);
+    let is_component = |name: &str| -> bool {
+        COMPONENTS.contains(&name) ||
+            local_components.iter().flatten().any(|(c, _)| to_kebab_case(c) == name) ||
+            config.global.components.iter().any(|c| to_kebab_case(c) == name) ||
+            global.components.keys().any(|c| to_kebab_case(c) == name)
+    };
+    if let Some(template) = component.template() {
+        a11y::check_a11y(&template.syntax, true, results);
+        security::check_template(&template.syntax, results);
+        // N.B. if the script doesn't parse, its components aren't known, so unknown elements aren't reported
+        let is_component = |tag: &str| is_component(&to_kebab_case(tag));
+        let is_component = local_components.as_ref().map(|_| &is_component as &dyn Fn(&str) -> bool);
+        content::check_template(&template.syntax, is_component, results);
+    }
 
     // Check all expressions in the template have valid syntax
-    let (expr_ranges, decl_ranges) = component.template()
-        .map(collect_template_scope)
-        .unwrap_or_default();
     let mut template_expressions = Vec::new();
-    for range in expr_ranges {
-        let raw_expr = &db.source_text(src_id)[range];
-        let trim_offset = raw_expr.chars().take_while(|&c| c.is_whitespace()).count();
-        let trim_range = range + TextUnit::from_usize(trim_offset);
-        let trim_expr = raw_expr.trim();
-        let (expr, _) = js::Expression::parse(trim_expr);
-        let errors = expr.errors();
-        if errors.is_empty() {
-            template_expressions.push((expr, trim_range));
-        } else {
-            syntax_errors(&mut results, db, src_id, path.as_str(), trim_range.start(), errors);
-        }
-    }
     let mut template_declarations = Vec::new();
-    for (scope_range, ident_range) in decl_ranges {
-        let raw_expr = &db.source_text(src_id)[ident_range];
-        template_declarations.push((raw_expr.to_string(), scope_range, ident_range));
+    let mut template_parsed = component.template().is_some();
+    for &expr_id in db.template_expressions(src_id).iter() {
+        let expr = db.lookup_intern_template_expression(expr_id);
+        let code = db.template_code(expr_id);
+        let errors = code.errors(expr.kind);
+        if !errors.is_empty() {
+            syntax_errors(db, results, db.template_expression_source(expr_id), errors);
+            template_parsed = false;
+            continue;
+        }
+        if let TemplateCode::Pattern(pattern) = &code {
+            let mut decls = Vec::new();
+            collect_pattern_decls_and_captures(pattern, &mut decls, &mut Captures::default(), true);
+            template_declarations.extend(decls.into_iter().map(|decl| (SmolStr::from(decl), expr.scope)));
+        }
+        // N.B. patterns are checked too, because their default values may capture variables (e.g. `{ item = fallback }`)
+        template_expressions.push((code.clone(), db.template_expression_source(expr_id)));
     }
 
     // Find the component script
     let (source_id, _) = match db.component_script(src_id) {
         Some(id) => id,
-        None => return results,
+        None => return false,
     };
     let root = db.typescript_ast(source_id);
-    let script_block = component.script().unwrap().script().unwrap();
-    let script_pos = script_block.syntax.range().start();
+    let script_pos = original_position(db, source_id, TextUnit::default()).map(|pos| pos.offset).unwrap_or_default();
     {
         let errors = root.errors();
         if !errors.is_empty() {
-            syntax_errors(&mut results, db, src_id, path.as_str(), script_pos, errors);
-            return results
+            syntax_errors(db, results, source_id, errors);
+            return false;
         }
     }
+    imports::check_imports(db, file_id, &root, script_pos, results);
+    security::check_script(&root, script_pos, results);
 
-    let maybe_default_export = root.syntax.children().find_map(ts::ExportDefaultDeclaration::cast);
-    let maybe_default_expr = maybe_default_export.and_then(|n| n.syntax.children().find_map(ts::Expression::cast));
-    let maybe_options = maybe_default_expr.and_then(|expr| match expr.kind() {
-        ts::ExpressionKind::CallExpression(call) => {
-            let maybe_vue_extend = call.syntax.first_child().and_then(ts::MemberExpression::cast)?;
-            let maybe_vue = maybe_vue_extend.syntax.first_token()?;
-            let maybe_extend = maybe_vue_extend.syntax.last_token()?;
-            if maybe_vue.text() != "Vue" || maybe_extend.text() != "extend" {
-                return None;
-            }
-            let start_args = call.syntax.children_with_tokens().find(|c| c.kind() == L_PAREN)?;
-            start_args.next_sibling_or_token()
-                .and_then(|el| match el {
-                    SyntaxElement::Node(node) => Some(node),
-                    SyntaxElement::Token(_) => None,
-                })
-                .and_then(ts::ObjectExpression::cast)
-        }
-        ts::ExpressionKind::ObjectExpression(object) => Some(object),
-        _ => None,
-    });
-    let vue_options = match maybe_options {
+    let vue_options = match component_options(&root) {
         Some(object) => object,
-        None => return results,
+        None => return false,
     };
 
     // Compute the `vm` (ViewModel) properties/accessors.
+    // N.B. if the `vm` isn't known (e.g. it extends an unresolved component), only its properties aren't checked
+    let mut vm = component_vm(db, file_id, vue_options, script_pos, results, 0).ok();
+    inject::check_injections(db, file_id, vue_options, script_pos, results);
+
+    let vue_filters = get_object_property(vue_options, "filters")
+        .map(AstNode::syntax)
+        .and_then(ts::Expression::cast)
+        .map(infer_expression_type)
+        .and_then(|ty| match ty {
+            Ty::Interface(ty) => Some(ty),
+            _ => None,
+        })
+        .unwrap_or_else(|| InterfaceTy::default().into());
+
+    // Instance properties added by plugins have a lower precedence than any other properties
+    if let Some(vm) = &mut vm {
+        let mut tmp = InterfaceTy::default();
+        tmp.typeof_ = Some(vec![TypeOf::Object].into());
+        let instance_properties = global.instance_properties.keys().map(|p| p.as_str())
+            .chain(config.global.properties.iter().map(|p| p.as_str()));
+        for ident in instance_properties {
+            tmp.properties.push(PropertyDef { ident: ident.into(), type_: Ty::Any.into() });
+        }
+        tmp.merge(vm);
+        *vm = tmp;
+    }
+
+    options::check_options(vue_options, &config, script_pos, results);
+    members::check_members(vue_options, script_pos, results);
+    if let Some(vm) = &vm {
+        watch::check_watchers(vue_options, vm, script_pos, results);
+    }
+
+    // Check that all directives used in the template are defined
+    let local_directives: Vec<SmolStr> = get_object_property(vue_options, "directives")
+        .and_then(ts::ObjectExpression::downcast)
+        .map(|obj| obj.properties().filter_map(infer_property_name).collect())
+        .unwrap_or_default();
+    let is_directive = |name: &str| -> bool {
+        DIRECTIVES.contains(&name) ||
+            local_directives.iter().any(|d| to_kebab_case(d) == name) ||
+            config.global.directives.iter().any(|d| to_kebab_case(d) == name) ||
+            global.directives.keys().any(|d| to_kebab_case(d) == name)
+    };
+    if let Some(template) = component.template() {
+        for directive in template.syntax.descendants().filter_map(vue::Directive::cast) {
+            let name = directive.name();
+            if !is_directive(name) {
+                results.push(Diagnostic::error("vue", directive.syntax.range().start(), format!("directive `v-{}` is not defined", name)));
+            }
+        }
+    }
+
+    // Check that dynamic arguments (e.g. `:[key]`) are valid attribute names
+    if let Some(template) = component.template() {
+        for key in template.syntax.descendants().filter_map(vue::AttributeKey::cast) {
+            if !key.is_dynamic() {
+                continue;
+            }
+            let pos = key.syntax.range().start();
+            if key.syntax.children_with_tokens().any(|syn| syn.kind() == WS) {
+                results.push(Diagnostic::error("vue", pos, "dynamic arguments can't contain spaces"));
+            } else if key.syntax.children_with_tokens().any(|syn| syn.kind() == QUOTED) {
+                results.push(Diagnostic::error("vue", pos, "dynamic arguments can't contain quotes"));
+            } else if key.syntax.preorder_with_tokens().any(|event| match event {
+                WalkEvent::Enter(SyntaxElement::Token(token)) => token.text().chars().any(char::is_uppercase),
+                _ => false,
+            }) {
+                let message = "dynamic arguments shouldn't contain uppercase letters, because browsers lowercase attribute names in in-DOM templates";
+                results.push(Diagnostic::warn("pedantic", pos, message));
+            }
+        }
+    }
+
+    // Check that all components used in the template are registered
+    if let Some(template) = component.template() {
+        for token in element_tag_names(&template.syntax) {
+            // N.B. only names which can't be html elements are checked (e.g. `my-component` or `MyComponent`)
+            let tag = token.text().as_str();
+            if !tag.contains('-') && !tag.starts_with(|c: char| c.is_uppercase()) {
+                continue;
+            }
+            let name = to_kebab_case(tag);
+            if !is_component(&name) {
+                results.push(Diagnostic::error("vue", token.range().start(), format!("component `{}` is not defined", tag)));
+            }
+        }
+    }
+
+    if let Some(template) = component.template() {
+        model::check_models(db, file_id, template, vue_options, results);
+        modifiers::check_modifiers(db, file_id, template, &config, results);
+    }
+
+    // Check that all expressions in the template reference known vm properties
+    let is_decl_in_template = |name: &str, range: TextRange| -> bool {
+        template_declarations.iter().any(|(decl, scope)| decl == name && range.is_subrange(scope))
+    };
+    let unknown_vm = InterfaceTy::default(); // N.B. the methods of an unknown `vm` aren't checked
+    let known_vm = vm.as_ref().unwrap_or(&unknown_vm);
+    calls::check_calls(db, &template_expressions, known_vm, &is_decl_in_template, results);
+    if let Some(template) = component.template() {
+        calls::check_listeners(db, file_id, template, known_vm, &is_decl_in_template, results);
+    }
+    let mut template_references = FxHashSet::default();
+    let mut template_walked = true;
+    for (code, expr_src_id) in template_expressions {
+        let captures = code.captures();
+        template_walked &= !captures.incomplete;
+        for (ident, node) in captures.refs {
+            template_references.insert(SmolStr::from(ident));
+            let range = match original_range(db, expr_src_id, node.syntax.range()) {
+                Some(original) => original.range,
+                None => continue,
+            };
+            if vm.is_some() &&
+                !known_vm.properties.iter().any(|p| p.ident == ident) &&
+                !ident.starts_with('$') &&
+                !is_global(ident) &&
+                !is_decl_in_template(ident, range) &&
+                // TODO: Only perform these check if the expression is in a filter
+                !vue_filters.properties.iter().any(|p| p.ident == ident) &&
+                !config.global.filters.iter().any(|f| f == ident) &&
+                !global.filters.contains_key(ident)
+            {
+                results.push(Diagnostic::error("vue", range.start(), format!("property `{}` is not defined on the component", ident)));
+            }
+        }
+    }
+
+    // N.B. a member can only be reported as unused if every reference in the template was found
+    if template_parsed && template_walked {
+        members::check_unused(db, file_id, vue_options, &template_references, script_pos, results);
+    }
+
+    // ==== TODOs =====
+    // 1. Check the `this.{property_name}` references exist in Vue apollo functions
+    //
+    // 2. Check whether the methods and properties accessed in the DOM exist in the corresponding VM property's type
+    //
+    template_parsed && vm.is_some()
+}
+
+/// Infers the properties of a component's `vm` from its options (e.g. `props`, `data` and `methods`).
+///
+/// Returns `None` if the `vm` can't be inferred precisely enough to check the template.
+fn infer_vm(vue_options: &ts::ObjectExpression, base: TextUnit, results: &mut Vec<Diagnostic>) -> Option<InterfaceTy> {
     let mut vm = InterfaceTy::default();
     vm.typeof_ = Some(vec![TypeOf::Object].into());
     match get_object_property(vue_options, "props") {
-        Some(options) => match infer_props_types(options) {
+        Some(options) => match infer_props_types(options, base) {
             Ok((partial, warnings)) => {
                 results.extend(warnings);
                 vm.merge(&partial);
             }
             Err(errors) => {
                 results.extend(errors);
-                return results;
+                return None;
             }
         },
         None => (),
@@ -118,19 +351,24 @@ pub(crate) fn check(db: &impl VueDatabase, file_id: FileId) -> Vec<String> {
         .map(infer_expression_type);
     if vue_mixins.is_some() {
         // TODO: Lookup mixin.... and mix it in!
-        return results;
+        return None;
     }
     let vue_data_property = get_object_property(vue_options, "data");
     let vue_data = vue_data_property
         .and_then(|expr| {
             match expr.kind() {
-                ts::ExpressionKind::ObjectExpression(object) => Some(object.into()),
+                ts::ExpressionKind::ObjectExpression(object) => {
+                    // N.B. a plain object would be shared between every instance of the component
+                    let pos = base + object.syntax.range().start();
+                    results.push(Diagnostic::error("correctness", pos, "vue component `data` must be a function which returns an object"));
+                    Some(object.into())
+                }
                 ts::ExpressionKind::FunctionExpression(func) => Some(func)
                     .and_then(|f| f.body())
                     .and_then(|f| f.body().last())
                     .and_then(|f| ts::ReturnStatement::cast(&f.syntax).or_else(|| {
-                        let pos = error_at(db, src_id, path.as_str(), script_pos + f.syntax.range().start());
-                        results.push(format!("warn(internal): [{}] could not find `return ...` in component's `data` method", pos));
+                        let pos = base + f.syntax.range().start();
+                        results.push(Diagnostic::warn("internal", pos, "could not find `return ...` in component's `data` method"));
                         None
                     }))
                     .and_then(|f| f.argument()),
@@ -150,9 +388,9 @@ pub(crate) fn check(db: &impl VueDatabase, file_id: FileId) -> Vec<String> {
     if let Some(partial) = vue_data.as_ref().and_then(Ty::as_interface) {
         vm.merge(partial);
     }  else if let Some(data) = vue_data_property {
-        let pos = error_at(db, src_id, path.as_str(), script_pos + data.syntax.range().start());
-        results.push(format!("warn(internal): [{}] could not infer type of component's `data`", pos));
-        return results;
+        let pos = base + data.syntax.range().start();
+        results.push(Diagnostic::warn("internal", pos, "could not infer type of component's `data`"));
+        return None;
     }
     let vue_computed = get_object_property(vue_options, "computed")
         .map(AstNode::syntax)
@@ -174,15 +412,6 @@ pub(crate) fn check(db: &impl VueDatabase, file_id: FileId) -> Vec<String> {
     if let Some(partial) = vue_methods.as_ref().and_then(Ty::as_interface) {
         vm.merge(partial);
     }
-    let vue_filters = get_object_property(vue_options, "filters")
-        .map(AstNode::syntax)
-        .and_then(ts::Expression::cast)
-        .map(infer_expression_type)
-        .and_then(|ty| match ty {
-            Ty::Interface(ty) => Some(ty),
-            _ => None,
-        })
-        .unwrap_or_else(|| InterfaceTy::default().into());
 
     // TODO: Move `vue_store` into some sort of `extensions` or `contrib` module
     let vue_apollo = get_object_property(vue_options, "store")
@@ -217,52 +446,92 @@ pub(crate) fn check(db: &impl VueDatabase, file_id: FileId) -> Vec<String> {
         vm = tmp;
     }
 
-    // Check that all expressions in the template reference known vm properties
-    let root_id = db.file_source_root(file_id);
-    let config = db.vue_config(root_id);
-    let global = db.global_registry(root_id);
-    let is_decl_in_template = |name: &str, range: TextRange| -> bool {
-        template_declarations.iter().any(|(decl, scope, _item)| decl == name && range.is_subrange(scope))
-    };
-    for (expr, range) in template_expressions {
-        for (ident, node) in find_captured_environment(&expr) {
-            if !vm.properties.iter().any(|p| p.ident == ident) &&
-                !ident.starts_with('$') &&
-                !is_global(ident) &&
-                !is_decl_in_template(ident, node.syntax.range() + range.start()) &&
-                // TODO: Only perform these check if the expression is in a filter
-                !vue_filters.properties.iter().any(|p| p.ident == ident) &&
-                !config.global.filters.iter().any(|f| f == ident) &&
-                !global.filters.contains(ident)
-            {
-                let pos = error_at(db, src_id, path.as_str(), range.start() + node.syntax.range().start());
-                results.push(format!("error(vue): [{}] property `{}` is not defined on the component", pos, ident));
-            }
+    // Injected properties are resolved before (so have a lower precedence than) the component's own properties
+    let injections = inject::injections(vue_options);
+    if !injections.is_empty() {
+        let mut tmp = InterfaceTy::default();
+        tmp.typeof_ = Some(vec![TypeOf::Object].into());
+        for injection in injections {
+            tmp.properties.push(PropertyDef { ident: injection.name, type_: Ty::Any.into() });
         }
+        tmp.merge(&vm);
+        vm = tmp;
     }
 
-    // ==== TODOs =====
-    // 1. Check that all `bullet-case` and `CamelCase` DOM tags are define
-    //    globally in the project (via `Vue.component`) or included as a `Component`.
-    //
-    // 2. Check the `this.{property_name}` references exist in Vue apollo functions
-    //
-    // 3. Check whether the methods and properties accessed in the DOM exist in the corresponding VM property's type
-    //
-
-    results
+    Some(vm)
 }
 
-fn syntax_errors(
-    results: &mut Vec<String>,
+/// Infers the `vm` of a component from its options, including any properties inherited with `extends`.
+///
+/// Returns `Err` if the `vm` (or the `vm` of the extended component) can't be inferred.
+fn component_vm(
     db: &impl VueDatabase,
-    src_id: SourceId,
-    filename: &str,
+    file_id: FileId,
+    vue_options: &ts::ObjectExpression,
     base: TextUnit,
-    errors: Vec<SyntaxError>,
-) {
-    // TODO: include filename in error message
-    // let filename = db.input_filename(src_id);
+    results: &mut Vec<Diagnostic>,
+    depth: usize,
+) -> Result<InterfaceTy, ()> {
+    let vm = infer_vm(vue_options, base, results).ok_or(())?;
+    let extends = match get_object_property(vue_options, "extends") {
+        Some(extends) => extends,
+        None => return Ok(vm),
+    };
+    if depth >= MAX_EXTENDS_DEPTH {
+        return Err(()); // N.B. probably a cycle of components extending each other
+    }
+
+    // Properties inherited with `extends` have a lower precedence than the component's own properties
+    let mut inherited = match find_definition(db, file_id, extends) {
+        Definition::Expression(ast_id) => {
+            // N.B. the options are defined in this script (e.g. `extends: { ... }` or `const Base = { ... }`)
+            let program = db.typescript_ast(ast_id.file_id());
+            let source_map = db.typescript_source_map(ast_id.file_id());
+            let object = ts::ObjectExpression::downcast(source_map.find_in_root(&program.syntax, ast_id)).ok_or(())?;
+            component_vm(db, file_id, object, base, results, depth + 1)?
+        }
+        definition => {
+            let target = definition.resolve_file(db, file_id).ok_or(())?;
+            let program = script_source(db, target).map(|src_id| db.typescript_ast(src_id)).ok_or(())?;
+            if !program.errors().is_empty() {
+                return Err(());
+            }
+            let object = match &definition {
+                Definition::Import { name, .. } => exported_options(&program, name),
+                _ => component_options(&program),
+            };
+            let object = object.ok_or(())?;
+
+            // N.B. diagnostics belong to the extended component's file, so they're discarded here
+            component_vm(db, target, object, TextUnit::default(), &mut Vec::new(), depth + 1)?
+        }
+    };
+    inherited.merge(&vm);
+    Ok(inherited)
+}
+
+/// The maximum number of components which can be chained by `extends`.
+const MAX_EXTENDS_DEPTH: usize = 8;
+
+/// Finds the components registered by a component's script, or `None` if its script can't be parsed.
+fn script_components(db: &impl VueDatabase, file_id: FileId, src_id: SourceId) -> Option<Vec<(SmolStr, Registration)>> {
+    let (source_id, _) = match db.component_script(src_id) {
+        Some(id) => id,
+        None => return Some(Vec::new()),
+    };
+    let root = db.typescript_ast(source_id);
+    if !root.errors().is_empty() {
+        return None;
+    }
+    let components = component_options(&root)
+        .and_then(|options| get_object_property(options, "components"))
+        .and_then(ts::ObjectExpression::downcast)
+        .map(|obj| local_components(db, file_id, obj))
+        .unwrap_or_default();
+    Some(components)
+}
+
+fn syntax_errors(db: &impl VueDatabase, results: &mut Vec<Diagnostic>, src_id: SourceId, errors: Vec<SyntaxError>) {
     let mut offset_set = FxHashSet::default();
     results.extend(errors.into_iter().filter_map(|err| {
         // Only display the first _syntax_ error for each line.
@@ -270,8 +539,8 @@ fn syntax_errors(
         let offset = err.offset();
         if !offset_set.contains(&offset) {
             offset_set.insert(offset);
-            let pos = error_at(db, src_id, filename, base + offset);
-            Some(format!("error(syntax): [{}] {}", pos, err.message))
+            let pos = original_position(db, src_id, offset).map(|pos| pos.offset).unwrap_or(offset);
+            Some(Diagnostic::error("syntax", pos, err.message))
         } else {
             None
         }
@@ -284,24 +553,79 @@ fn error_at(db: &impl VueDatabase, src_id: SourceId, filename: &str, pos: TextUn
     format!("{}:{}:{}", filename, line_col.line + 1, line_col.col_utf16 + 1)
 }
 
+impl TemplateCode {
+    /// Find all of the variables captured by the code, like `find_captured_environment`.
+    fn captures(&self) -> Captures {
+        match self {
+            TemplateCode::Expression(expr) => find_captured_environment(expr),
+            TemplateCode::Statements(program) => {
+                let mut decls = Vec::new();
+                let mut captures = Captures::default();
+                for stmt in program.syntax.children().filter_map(ts::Statement::cast) {
+                    collect_statement_decls_and_captures(stmt, &mut decls, &mut captures);
+                }
+                captures
+            }
+            TemplateCode::Pattern(pattern) => {
+                let mut decls = Vec::new();
+                let mut captures = Captures::default();
+                collect_pattern_decls_and_captures(pattern, &mut decls, &mut captures, true);
+                captures
+            }
+        }
+    }
+}
+
+/// Finds the parsed code of a directive's value with the source it was parsed from, unless the
+/// code has syntax errors (which are reported separately).
+fn directive_code(db: &impl VueDatabase, src_id: SourceId, directive: &vue::Directive) -> Option<(TemplateCode, SourceId)> {
+    let range = directive.value_range()?;
+    db.template_expressions(src_id).iter().find_map(|&expr_id| {
+        let expr = db.lookup_intern_template_expression(expr_id);
+        if expr.kind == TemplateExpressionKind::Pattern || !expr.range.is_subrange(&range) {
+            return None;
+        }
+        let code = db.template_code(expr_id);
+        if !code.errors(expr.kind).is_empty() {
+            return None;
+        }
+        Some((code, db.template_expression_source(expr_id)))
+    })
+}
+
 /// Find all of the variables captured by a closure (or other expression),
 /// returning a reference to each site that a captured variable is referenced.
 ///
 /// This works by finding all "global" or undeclared variables in an expression,
 /// including references to `this`; which has uses in other contexts outside
 /// of closure expressions.
-fn find_captured_environment(expr: &ts::Expression) -> Vec<(&str, &ts::Expression)> {
-    let mut captures = Vec::new();
+fn find_captured_environment(expr: &ts::Expression) -> Captures {
+    let mut captures = Captures::default();
     collect_captures(expr, &[], &mut captures);
     captures
 }
 
+/// The variables captured by some code, with each site that they're referenced.
+#[derive(Default)]
+pub(super) struct Captures<'a> {
+    pub refs: Vec<(&'a str, &'a ts::Expression)>,
+    /// Whether some of the code couldn't be walked (e.g. the body of a `with` statement),
+    /// so there may be captured variables which are missing from `refs`.
+    pub incomplete: bool,
+}
+
+impl<'a> Captures<'a> {
+    fn push(&mut self, capture: (&'a str, &'a ts::Expression)) {
+        self.refs.push(capture);
+    }
+}
+
 
 #[inline(always)]
 fn maybe_collect_captures<'a>(
     expr: Option<&'a ts::Expression>,
     decls: &[&'a str],
-    captures: &mut Vec<(&'a str, &'a ts::Expression)>,
+    captures: &mut Captures<'a>,
 ) {
     if let Some(expr) = expr {
         collect_captures(expr, decls, captures)
@@ -310,7 +634,7 @@ fn maybe_collect_captures<'a>(
 fn collect_captures<'a>(
     expr: &'a ts::Expression,
     decls: &[&'a str],
-    captures: &mut Vec<(&'a str, &'a ts::Expression)>,
+    captures: &mut Captures<'a>,
 ) {
     match expr.kind() {
         ts::ExpressionKind::Identifier(node) => {
@@ -320,10 +644,11 @@ fn collect_captures<'a>(
             }
         }
         ts::ExpressionKind::Literal(node) => {
-            match node.kind() {
-                // TODO: Detect expressions used inside templates
-                ts::LiteralKind::Template(_token) => (),
-                _ => (),
+            // N.B. the substitutions of a template literal aren't parsed (e.g. `${count}`)
+            if let ts::LiteralKind::Template(token) = node.kind() {
+                if token.text().contains("${") {
+                    captures.incomplete = true;
+                }
             }
         }
         ts::ExpressionKind::ThisExpression(_) => {
@@ -333,6 +658,7 @@ fn collect_captures<'a>(
             for el in node.elements() {
                 collect_captures(el, decls, captures);
             }
+            collect_spread_captures(&node.syntax, decls, captures);
         }
         ts::ExpressionKind::ObjectExpression(node) => {
             for prop in node.properties() {
@@ -341,6 +667,7 @@ fn collect_captures<'a>(
                 }
                 maybe_collect_captures(prop.value(), decls, captures);
             }
+            collect_spread_captures(&node.syntax, decls, captures);
         },
         ts::ExpressionKind::FunctionExpression(node) => {
             let mut fn_decls = decls.to_vec();
@@ -388,12 +715,14 @@ fn collect_captures<'a>(
             for arg in node.arguments() {
                 collect_captures(arg, decls, captures);
             }
+            collect_spread_captures(&node.syntax, decls, captures);
         }
         ts::ExpressionKind::NewExpression(node) => {
             maybe_collect_captures(node.callee(), decls, captures);
             for arg in node.arguments() {
                 collect_captures(arg, decls, captures);
             }
+            collect_spread_captures(&node.syntax, decls, captures);
         }
         ts::ExpressionKind::SequenceExpression(node) => {
             for expr in node.expressions() {
@@ -418,27 +747,43 @@ fn collect_captures<'a>(
         ts::ExpressionKind::YieldExpression(node) => {
             maybe_collect_captures(node.argument(), decls, captures);
         }
-        ts::ExpressionKind::TemplateLiteral(_node) => (), // TODO: Detect expressions used inside template literals
+        ts::ExpressionKind::TemplateLiteral(node) => {
+            // N.B. the substitutions of a template literal aren't parsed (e.g. `${count}`)
+            if node.syntax.first_token().map(|token| token.text().contains("${")).unwrap_or(true) {
+                captures.incomplete = true;
+            }
+        }
         ts::ExpressionKind::TaggedTemplateExpression(node) => {
             maybe_collect_captures(node.tag(), decls, captures);
-            // TODO: Detect expressions used inside template literals
+            captures.incomplete = true;
         }
-        ts::ExpressionKind::ClassExpression(_node) => (), // TODO: Implement
-        ts::ExpressionKind::MetaProperty(_node) => (), // TODO: Implement
+        ts::ExpressionKind::ClassExpression(_node) => captures.incomplete = true,
+        ts::ExpressionKind::MetaProperty(_node) => (),
         ts::ExpressionKind::AwaitExpression(node) => {
             maybe_collect_captures(node.argument(), decls, captures);
         }
 
-        ts::ExpressionKind::TSAsExpression(_) |
-        ts::ExpressionKind::TSNonNullExpression(_) => {
-            unreachable!() // N.B. typescript isn't valid in the template
+        // N.B. typescript isn't valid in the template, but it's still parsed (e.g. `value as string`)
+        ts::ExpressionKind::TSAsExpression(ts::TSAsExpression { syntax, .. }) |
+        ts::ExpressionKind::TSNonNullExpression(ts::TSNonNullExpression { syntax, .. }) => {
+            maybe_collect_captures(syntax.first_child().and_then(ts::Expression::cast), decls, captures);
         }
     }
 }
+/// Collects the captures of the spread arguments or elements of an expression (e.g. `go(...items)`).
+fn collect_spread_captures<'a>(
+    syntax: &'a SyntaxNode,
+    decls: &[&'a str],
+    captures: &mut Captures<'a>,
+) {
+    for spread in syntax.children().filter_map(ts::SpreadElement::cast) {
+        maybe_collect_captures(spread.syntax.children().find_map(ts::Expression::cast), decls, captures);
+    }
+}
 fn collect_block_captures<'a> (
     block: &'a ts::BlockStatement,
     decls: &[&'a str],
-    captures: &mut Vec<(&'a str, &'a ts::Expression)>,
+    captures: &mut Captures<'a>,
 ) {
     let mut decls = decls.to_vec();
     for stmt in block.body() {
@@ -461,7 +806,7 @@ fn collect_block_captures<'a> (
 fn collect_statement_decls_and_captures<'a>(
     stmt: &'a ts::Statement,
     decls: &mut Vec<&'a str>,
-    captures: &mut Vec<(&'a str, &'a ts::Expression)>,
+    captures: &mut Captures<'a>,
 ) {
     match stmt.kind() {
         ts::StatementKind::ExpressionStatement(node) => {
@@ -473,8 +818,14 @@ fn collect_statement_decls_and_captures<'a>(
         ts::StatementKind::EmptyStatement(_) => (),
         ts::StatementKind::DebuggerStatement(_) => (),
 
-        // TODO: See https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/with
-        ts::StatementKind::WithStatement(_) => unimplemented!(),
+        // N.B. the properties of the object are in scope in the body, so its captures can't be known
+        //      (see https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/with)
+        ts::StatementKind::WithStatement(node) => {
+            if let Some(object) = node.syntax.children().find_map(ts::Expression::cast) {
+                collect_captures(object, decls, captures);
+            }
+            captures.incomplete = true;
+        }
 
         ts::StatementKind::ReturnStatement(node) => {
             maybe_collect_captures(node.argument(), decls, captures);
@@ -486,17 +837,27 @@ fn collect_statement_decls_and_captures<'a>(
         }
         ts::StatementKind::BreakStatement(_) => (),
         ts::StatementKind::ContinueStatement(_) => (),
-        // IfStatement = IF_STATEMENT,
-        // SwitchStatement = SWITCH_STATEMENT,
+        ts::StatementKind::IfStatement(node) => {
+            maybe_collect_captures(node.test(), decls, captures);
+            if let Some(stmt) = node.consequent() {
+                collect_statement_decls_and_captures(stmt, decls, captures);
+            }
+            if let Some(stmt) = node.alternate() {
+                collect_statement_decls_and_captures(stmt, decls, captures);
+            }
+        }
         ts::StatementKind::ThrowStatement(node) => {
             maybe_collect_captures(node.argument(), decls, captures);
         }
-        // TryStatement = TRY_STATEMENT,
-        // WhileStatement = WHILE_STATEMENT,
-        // DoWhileStatement = DO_WHILE_STATEMENT,
-        // ForStatement = FOR_STATEMENT,
-        // ForInStatement = FOR_IN_STATEMENT,
-        // ForOfStatement = FOR_OF_STATEMENT,
+        ts::StatementKind::SwitchStatement(_) |
+        ts::StatementKind::TryStatement(_) |
+        ts::StatementKind::WhileStatement(_) |
+        ts::StatementKind::DoWhileStatement(_) |
+        ts::StatementKind::ForStatement(_) |
+        ts::StatementKind::ForInStatement(_) |
+        ts::StatementKind::ForOfStatement(_) => {
+            collect_nested_captures(&stmt.syntax, &mut decls.clone(), captures);
+        }
         ts::StatementKind::Declaration(decl) => {
             match decl.kind() {
                 ts::DeclarationKind::FunctionDeclaration(node) => {
@@ -522,18 +883,45 @@ fn collect_statement_decls_and_captures<'a>(
                 ts::DeclarationKind::ClassDeclaration(node) => {
                     decls.push(node.id().name());
                     // TODO: Probably we need to recurse into the definition of the class here...
+                    captures.incomplete = true;
                 }
             }
         }
-        _ => {
-            // TODO: Implement all the block variations (If, Switch, Try, While, DoWhile, For, ForIn, ForOf)
+    }
+}
+/// Collects the captures of the statements and expressions in a compound statement (e.g. the
+/// test and body of a loop), where `decls` are the declarations in the statement's scope.
+fn collect_nested_captures<'a>(
+    syntax: &'a SyntaxNode,
+    decls: &mut Vec<&'a str>,
+    captures: &mut Captures<'a>,
+) {
+    for child in syntax.children() {
+        if let Some(stmt) = ts::Statement::cast(child) {
+            collect_statement_decls_and_captures(stmt, decls, captures);
+        } else if let Some(expr) = ts::Expression::cast(child) {
+            collect_captures(expr, decls, captures);
+        } else if let Some(case) = ts::SwitchCase::cast(child) {
+            // N.B. the cases of a `switch` share the same scope
+            collect_nested_captures(&case.syntax, decls, captures);
+        } else if let Some(clause) = ts::CatchClause::cast(child) {
+            let mut catch_decls = decls.clone();
+            for child in clause.syntax.children() {
+                if let Some(block) = ts::BlockStatement::cast(child) {
+                    collect_block_captures(block, &catch_decls, captures);
+                } else if let Some(param) = ts::Pattern::cast(child) {
+                    collect_pattern_decls_and_captures(param, &mut catch_decls, captures, true);
+                }
+            }
+        } else {
+            captures.incomplete = true;
         }
     }
 }
 fn collect_pattern_decls_and_captures<'a>(
     pat: &'a ts::Pattern,
     decls: &mut Vec<&'a str>,
-    captures: &mut Vec<(&'a str, &'a ts::Expression)>,
+    captures: &mut Captures<'a>,
     declaration: bool,
 ) {
     match pat.kind() {
@@ -553,222 +941,41 @@ fn collect_pattern_decls_and_captures<'a>(
         ts::PatternKind::ObjectPattern(obj) => {
             for prop in obj.properties() {
                 if let Some(pattern) = prop.value() {
-                    collect_pattern_decls_and_captures(pattern, decls, captures, true);
+                    collect_pattern_decls_and_captures(pattern, decls, captures, declaration);
                 }
             }
         }
         ts::PatternKind::ArrayPattern(arr) => {
-            for el in arr.elements() {
-                if let Some(pattern) = el {
-                    collect_pattern_decls_and_captures(pattern, decls, captures, true);
+            // N.B. a rest element in an array pattern is parsed as a spread (e.g. `[first, ...rest]`)
+            for child in arr.syntax.children() {
+                let pattern = match ts::SpreadElement::cast(child) {
+                    Some(spread) => spread.syntax.children().find_map(ts::Pattern::cast),
+                    None => ts::Pattern::cast(child),
+                };
+                if let Some(pattern) = pattern {
+                    collect_pattern_decls_and_captures(pattern, decls, captures, declaration);
                 }
             }
         }
-
-        // FIXME: Implement other patterns...
-        kind => {
-            eprintln!("UNIMPLEMENTED: {:?} of {}", kind, pat.syntax.parent().and_then(ts::Node::cast).unwrap().type_());
-            unimplemented!()
-        }
-    }
-}
-
-fn collect_template_scope(template: &vue::Template) -> (Vec<(TextRange)>, Vec<(TextRange, TextRange)>) {
-    let mut expressions = Vec::new();
-    let mut declarations = Vec::new();
-    for visit in template.syntax.preorder_with_tokens() {
-        let syn_elem = match visit {
-            WalkEvent::Enter(syntax) => syntax,
-            _ => continue,
-        };
-        match (syn_elem.kind(), syn_elem) {
-            (ATTRIBUTE, SyntaxElement::Node(node)) => {
-                let name = node.first_token().map(|tok| tok.text().as_str()).unwrap_or("");
-                match name {
-                    "slot-scope" => {
-                        let value = node.children_with_tokens()
-                            .skip_while(|syn| syn.kind() != EQ)
-                            .skip(1) // eat `EQ`
-                            .skip_while(|syn| syn.kind() == WS)
-                            .next();
-                        if let Some(SyntaxElement::Token(token)) = value {
-                            if token.kind() != QUOTED {
-                                continue; // N.B. not a valid `sloc-scope` value
-                            }
-
-                            // Parse the scope as a pattern
-                            let text = token.text().as_str();
-                            let range = token.range();
-                            let trimmed = text[1 .. text.len() - 1].trim();
-                            let trim_offset = text[1..].chars().take_while(|&c| c.is_whitespace()).count();
-                            let (pattern, _) = js::Pattern::parse(trimmed);
-                            if !pattern.errors().is_empty() {
-                                continue;
-                            }
-
-
-                            let mut pat_decls = Vec::new();
-                            let mut pat_captures = Vec::new();
-                            collect_pattern_decls_and_captures(&pattern, &mut pat_decls, &mut pat_captures, true);
-
-                            // TODO: Maybe also save _captures_ to expressions
-                            // captures: &mut Vec<(&'a str, &'a ts::Expression)>
-                            for decl in pat_decls {
-                                if let Some((decl_offset, _)) = text.match_indices(decl).next() {
-                                    let start = TextUnit::from_usize(range.start().to_usize() + trim_offset + decl_offset);
-                                    let end = TextUnit::from_usize(start.to_usize() + decl.len());
-                                    declarations.push((
-                                        node.parent().unwrap().range(),
-                                        TextRange::from_to(start, end)
-                                    ));
-                                }
-                            }
-                        }
-                    }
-                    "v-if" | "v-else-if" | "v-model" => {
-                        let value = node.children_with_tokens()
-                            .skip_while(|syn| syn.kind() != EQ)
-                            .skip(1) // eat `EQ`
-                            .skip_while(|syn| syn.kind() == WS)
-                            .next();
-                        if let Some(value) = value {
-                            if value.kind() == QUOTED {
-                                let range = value.range();
-                                let start = TextUnit::from_usize(range.start().to_usize() + 1);
-                                let end = TextUnit::from_usize(range.end().to_usize() - 1);
-                                expressions.push(TextRange::from_to(start, end));
-                            } else if value.kind() == IDENT {
-                                expressions.push(value.range());
-                            }
-                        }
-                    }
-                    "v-for" => {
-                        let value = node.children_with_tokens()
-                            .skip_while(|syn| syn.kind() != EQ)
-                            .skip(1) // eat `EQ`
-                            .skip_while(|syn| syn.kind() == WS)
-                            .next();
-                        if let Some(SyntaxElement::Token(token)) = value {
-                            if token.kind() != QUOTED {
-                                continue; // N.B. not a valid `v-for` value
-                            }
-
-                            // Define simple scanner
-                            let text = token.text().as_str();
-                            let range = token.range();
-                            let mut pos = 1; // skip '"' character
-                            let eat_whitespace = |pos: &mut usize| {
-                                while text.len() > *pos && text.chars().nth(*pos).map(char::is_whitespace).unwrap_or(false) {
-                                    *pos += 1;
-                                }
-                            };
-
-                            // Collect declarations
-                            eat_whitespace(&mut pos);
-                            if text.chars().nth(pos) == Some('(') {
-                                while text.len() > pos {
-                                    eat_whitespace(&mut pos);
-                                    if text.chars().nth(pos) == Some(')') {
-                                        pos += 1; // eat R_PAREN
-                                        break;
-                                    }
-                                    pos += 1; // eat L_PAREN or COMMA
-                                    eat_whitespace(&mut pos);
-
-                                    let len = text[pos..]
-                                        .chars()
-                                        .take_while(|&c| c != ',' && c != ')' && !c.is_whitespace())
-                                        .count();
-                                    let start = TextUnit::from_usize(range.start().to_usize() + pos);
-                                    let end = TextUnit::from_usize(start.to_usize() + len);
-                                    declarations.push((
-                                        node.parent().unwrap().range(),
-                                        TextRange::from_to(start, end),
-                                    ));
-                                    pos += len;
-                                }
-                            } else {
-                                eat_whitespace(&mut pos);
-                                let len = text[pos..]
-                                    .chars()
-                                    .take_while(|&c| c != ',' && c != ')' && !c.is_whitespace())
-                                    .count();
-                                let start = TextUnit::from_usize(range.start().to_usize() + pos);
-                                let end = TextUnit::from_usize(start.to_usize() + len);
-                                declarations.push((
-                                    node.parent().unwrap().range(),
-                                    TextRange::from_to(start, end),
-                                ));
-                                pos += len;
-                            }
-
-                            // Eat "in" keyword
-                            eat_whitespace(&mut pos);
-                            pos += text[pos..]
-                                .chars()
-                                .take_while(|&c| !c.is_whitespace())
-                                .count();
-                            eat_whitespace(&mut pos);
-
-                            // The rest is an expression
-                            let start = TextUnit::from_usize(range.start().to_usize() + pos);
-                            let end = TextUnit::from_usize(range.end().to_usize() - 1);
-                            expressions.push(TextRange::from_to(start, end));
-                        }
-                    }
-                    _ => (),
-                }
+        ts::PatternKind::AssignmentPattern(assign) => {
+            // N.B. the default value can't capture the declarations of its own pattern (e.g. `x = x`)
+            let outer_decls = decls.clone();
+            if let Some(pattern) = assign.syntax.first_child().and_then(ts::Pattern::cast) {
+                collect_pattern_decls_and_captures(pattern, decls, captures, declaration);
             }
-            (ATTRIBUTE_KEY, SyntaxElement::Node(node)) => {
-                let computed_key = node.children_with_tokens()
-                    .skip_while(|syn| syn.kind() != L_SQUARE)
-                    .skip(1) // eat `L_SQUARE`
-                    .skip_while(|syn| syn.kind() == WS)
-                    .next();
-                if let Some(ident) = computed_key {
-                    if ident.kind() != ERROR {
-                        assert_eq!(ident.kind(), IDENTIFIER);
-                        expressions.push(ident.range());
-                    }
-                }
-            }
-            (ATTRIBUTE_BINDING, SyntaxElement::Node(node)) |
-            (ATTRIBUTE_LISTENER, SyntaxElement::Node(node)) => {
-                let value = node.children_with_tokens()
-                    .skip_while(|syn| syn.kind() != EQ)
-                    .skip(1) // eat `EQ`
-                    .skip_while(|syn| syn.kind() == WS)
-                    .next();
-                if let Some(value) = value {
-                    if value.kind() == QUOTED {
-                        let range = value.range();
-                        let start = TextUnit::from_usize(range.start().to_usize() + 1);
-                        let end = TextUnit::from_usize(range.end().to_usize() - 1);
-                        expressions.push(TextRange::from_to(start, end));
-                    } else if value.kind() == IDENT {
-                        expressions.push(value.range());
-                    }
-                }
+            if let Some(default) = assign.syntax.last_child().and_then(ts::Expression::cast) {
+                collect_captures(default, &outer_decls, captures);
             }
-            (MUSTACHE, SyntaxElement::Token(token)) => {
-                let range = token.range();
-                let start = TextUnit::from_usize(range.start().to_usize() + 2);
-                let end = TextUnit::from_usize(range.end().to_usize() - 2);
-                expressions.push(TextRange::from_to(start, end));
+        }
+        ts::PatternKind::RestElement(rest) => {
+            if let Some(pattern) = rest.syntax.children().find_map(ts::Pattern::cast) {
+                collect_pattern_decls_and_captures(pattern, decls, captures, declaration);
             }
-            _ => (),
         }
     }
-    (expressions, declarations)
-}
-
-fn get_object_property<'a>(obj: &'a ts::ObjectExpression, key: &str) -> Option<&'a ts::Expression> {
-    obj.properties()
-        .find(|prop| infer_property_name(prop).as_ref().map(|x| x.as_str()) == Some(key))
-        .and_then(|prop| prop.value())
 }
 
-fn infer_props_types(props: &ts::Expression) -> Result<(InterfaceTy, Vec<String>), Vec<String>> {
+fn infer_props_types(props: &ts::Expression, base: TextUnit) -> Result<(InterfaceTy, Vec<Diagnostic>), Vec<Diagnostic>> {
     let mut object = InterfaceTy::default();
     let mut messages = Vec::new();
     match props.kind() {
@@ -783,7 +990,8 @@ fn infer_props_types(props: &ts::Expression) -> Result<(InterfaceTy, Vec<String>
                                 if ident.chars().all(|c| c.is_alphanumeric() || c == '_') {
                                     object.properties.push(PropertyDef { ident: ident.into(), type_: Ty::Any.into() });
                                 } else {
-                                    messages.push(format!("warn(style): vue `props` names should be valid identifiers, but found \"{}\"", text));
+                                    let pos = base + str_lit.range().start();
+                                    messages.push(Diagnostic::warn("style", pos, format!("vue `props` names should be valid identifiers, but found \"{}\"", text)));
                                 }
                                 continue;
                             }
@@ -791,14 +999,16 @@ fn infer_props_types(props: &ts::Expression) -> Result<(InterfaceTy, Vec<String>
                     }
                     _ => (),
                 }
-                messages.push("error(correctness): vue `props` array must be an array of strings".into());
+                let pos = base + el.syntax.range().start();
+                messages.push(Diagnostic::error("correctness", pos, "vue `props` array must be an array of strings"));
                 return Err(messages);
             }
         }
         ts::ExpressionKind::ObjectExpression(obj) => {
             for prop in obj.properties() {
                 if prop.computed() {
-                    messages.push("error(pedantic): vue `props` keys should not be computed, but got `[...]: ...`".into());
+                    let pos = base + prop.syntax.range().start();
+                    messages.push(Diagnostic::error("pedantic", pos, "vue `props` keys should not be computed, but got `[...]: ...`"));
                     continue;
                 }
                 let ident = match infer_property_name(prop) {
@@ -806,15 +1016,6 @@ fn infer_props_types(props: &ts::Expression) -> Result<(InterfaceTy, Vec<String>
                     None => continue,
                 };
                 let type_ = match prop.value().unwrap().kind() {
-                    ts::ExpressionKind::Identifier(ident) => {
-                        match ident.syntax.first_token().map(|t| t.text().as_str()) {
-                            Some("Array") => Ty::Union(vec![Ty::Array(Ty::Any.into()), Ty::Null, Ty::Undefined].into()),
-                            Some("String") => Ty::Union(vec![Ty::String, Ty::Null, Ty::Undefined].into()),
-                            Some("Object") => Ty::Union(vec![Ty::Object, Ty::Null, Ty::Undefined].into()),
-                            Some("Boolean") => Ty::Union(vec![Ty::Boolean, Ty::Null, Ty::Undefined].into()),
-                            _ => Ty::Hint(TypeOf::Null),
-                        }
-                    }
                     ts::ExpressionKind::ObjectExpression(prop_options) => {
                         let mut is_required = false;
                         if let Some(required) = get_object_property(prop_options, "required") {
@@ -826,38 +1027,44 @@ fn infer_props_types(props: &ts::Expression) -> Result<(InterfaceTy, Vec<String>
                                 "true" => is_required = true,
                                 "false" => is_required = false,
                                 text =>  {
-                                    messages.push(format!("error(pedantic): vue `prop.required` should be `true` or `false`, but got `{}`", text));
+                                    let pos = base + required.syntax.range().start();
+                                    messages.push(Diagnostic::error("pedantic", pos, format!("vue `prop.required` should be `true` or `false`, but got `{}`", text)));
                                 }
                             }
                         }
-                        // NOTE: incorrect but convenient to assume a default implies non-null
-                        let has_default = get_object_property(prop_options, "default").is_some();
-                        let maybe_type = get_object_property(prop_options, "type")
-                            .map(AstNode::syntax)
-                            .and_then(ts::Identifier::cast)
-                            .map(AstNode::syntax)
-                            .and_then(|x| x.first_token())
-                            .map(|x| x.text().as_str());
-                        let type_ = match maybe_type {
-                            Some("Array") => Ty::Array(Ty::Any.into()),
-                            Some("String") => Ty::String,
-                            Some("Object") => Ty::Object,
-                            Some("Boolean") => Ty::Boolean,
-                            _ => Ty::Any,
+                        let type_ = match get_object_property(prop_options, "type") {
+                            Some(type_) => infer_prop_type(type_, base, &mut messages),
+                            None => Ty::Any,
                         };
-                        if is_required || has_default {
+                        // NOTE: incorrect but convenient to assume a default implies non-null
+                        let default = get_object_property(prop_options, "default");
+                        if let Some(default) = default {
+                            match default.kind() {
+                                ts::ExpressionKind::ArrayExpression(_) | ts::ExpressionKind::ObjectExpression(_) => {
+                                    let pos = base + default.syntax.range().start();
+                                    messages.push(Diagnostic::error("correctness", pos, "vue `prop.default` for an `Array` or `Object` prop must be a factory function"));
+                                }
+                                _ => (),
+                            }
+                        }
+                        if is_required || default.is_some() {
                             type_
                         } else {
                             Ty::Union(vec![type_, Ty::Null, Ty::Undefined].into())
                         }
                     }
-                    _ => Ty::Any,
+                    ts::ExpressionKind::Literal(_) => Ty::Any,
+                    _ => {
+                        let type_ = infer_prop_type(prop.value().unwrap(), base, &mut messages);
+                        Ty::Union(vec![type_, Ty::Null, Ty::Undefined].into())
+                    }
                 };
                 object.properties.push(PropertyDef { ident: ident.into(), type_: type_.into() });
             }
         }
         _ => {
-            messages.push("error(pedantic): vue `props` must be an object or an array".into());
+            let pos = base + props.syntax.range().start();
+            messages.push(Diagnostic::error("pedantic", pos, "vue `props` must be an object or an array"));
             return Err(messages);
         }
     }
@@ -865,6 +1072,139 @@ fn infer_props_types(props: &ts::Expression) -> Result<(InterfaceTy, Vec<String>
     Ok((object, messages))
 }
 
+/// Constructors which vue can check a prop's value against.
+const PROP_TYPES: &[&str] = &["Array", "Boolean", "Date", "Function", "Number", "Object", "String", "Symbol"];
+
+/// Infers the type of a prop from its `type`, which may be a constructor, an array of
+/// constructors, or a constructor cast to a more specific type (e.g. `Object as PropType<User>`).
+fn infer_prop_type(type_: &ts::Expression, base: TextUnit, messages: &mut Vec<Diagnostic>) -> Ty {
+    match type_.kind() {
+        ts::ExpressionKind::Identifier(ident) => match ident.name() {
+            "Array" => Ty::Array(Ty::Any.into()),
+            "Boolean" => Ty::Boolean,
+            "Number" => Ty::Number,
+            "Object" => Ty::Object,
+            "String" => Ty::String,
+            name if PROP_TYPES.iter().any(|&known| known == name) => Ty::Any,
+            name => {
+                // N.B. vue accepts any constructor, but usually this is a mistyped built-in
+                let pos = base + ident.syntax.range().start();
+                let message = format!("vue `prop.type` should be one of {}, but got `{}`", PROP_TYPES.join(", "), name);
+                messages.push(Diagnostic::warn("pedantic", pos, message));
+                Ty::Any
+            }
+        },
+        ts::ExpressionKind::ArrayExpression(arr) => {
+            let types: Vec<Ty> = arr.elements().map(|el| infer_prop_type(el, base, messages)).collect();
+            if types.is_empty() || types.iter().any(|ty| match ty { Ty::Any => true, _ => false }) {
+                Ty::Any
+            } else if types.len() == 1 {
+                types.into_iter().next().unwrap()
+            } else {
+                Ty::Union(types.into())
+            }
+        }
+        ts::ExpressionKind::TSAsExpression(cast) => {
+            let constructor = cast.syntax.children().find_map(ts::Expression::cast);
+            let constructor_ty = match constructor {
+                Some(expr) => infer_prop_type(expr, base, messages),
+                None => Ty::Any,
+            };
+            let prop_type = cast.syntax.children()
+                .filter(|node| node.kind() == GENERIC_TYPE_EXPR)
+                .find(|node| node.first_child().and_then(|name| name.first_token()).map(|t| t.text() == "PropType").unwrap_or(false))
+                .and_then(|node| node.children().find(|arg| arg.kind() == TYPE_PARAMETER_INSTANTIATION))
+                .and_then(|arg| arg.first_child());
+            match prop_type {
+                Some(annotation) => infer_type_annotation(annotation),
+                None => constructor_ty,
+            }
+        }
+        // N.B. vue skips type checking for a `null` (or `undefined`) prop type
+        ts::ExpressionKind::Literal(lit) if lit.syntax.first_token().map(|t| t.kind() == NULL_KW).unwrap_or(false) => Ty::Any,
+        _ => {
+            let pos = base + type_.syntax.range().start();
+            messages.push(Diagnostic::warn("pedantic", pos, "vue `prop.type` should be a constructor or an array of constructors"));
+            Ty::Any
+        }
+    }
+}
+
+/// Converts the type annotation passed to `PropType<T>` into a type, falling back to `Any`
+/// for anything which would need to be resolved (e.g. interfaces and type aliases).
+fn infer_type_annotation(annotation: &SyntaxNode) -> Ty {
+    let name = || annotation.first_token().map(|t| t.text().clone()).unwrap_or_default();
+    match annotation.kind() {
+        IDENTIFIER => match name().as_str() {
+            "any" | "unknown" => Ty::Any,
+            "boolean" => Ty::Boolean,
+            "number" => Ty::Number,
+            "object" | "Object" => Ty::Object,
+            "string" => Ty::String,
+            "undefined" | "void" => Ty::Undefined,
+            "Array" => Ty::Array(Ty::Any.into()),
+            _ => Ty::Any,
+        },
+        LITERAL if name() == "null" => Ty::Null,
+        ARRAY_TYPE_EXPR => match annotation.first_child() {
+            Some(element) => Ty::Array(infer_type_annotation(element).into()),
+            None => Ty::Array(Ty::Any.into()),
+        },
+        GENERIC_TYPE_EXPR => {
+            let generic = annotation.first_child().and_then(|n| n.first_token()).map(|t| t.text().clone()).unwrap_or_default();
+            let mut args = annotation.children().filter(|n| n.kind() == TYPE_PARAMETER_INSTANTIATION).filter_map(|n| n.first_child());
+            match generic.as_str() {
+                "Array" | "ReadonlyArray" => Ty::Array(args.next().map(infer_type_annotation).unwrap_or(Ty::Any).into()),
+                "Record" | "Partial" | "Readonly" | "Required" => Ty::Object,
+                _ => Ty::Any,
+            }
+        }
+        UNION_TYPE_EXPR => {
+            let mut types = Vec::new();
+            for ty in annotation.children().map(infer_type_annotation) {
+                match ty {
+                    Ty::Union(nested) => types.extend(nested.iter().cloned()),
+                    ty => types.push(ty),
+                }
+            }
+            if types.is_empty() || types.iter().any(|ty| match ty { Ty::Any => true, _ => false }) {
+                Ty::Any
+            } else {
+                Ty::Union(types.into())
+            }
+        }
+        INTERFACE_TYPE_EXPR => Ty::Object,
+        _ => Ty::Any,
+    }
+}
+
+/// Directives provided by vue itself, without the `v-` prefix.
+const DIRECTIVES: &[&str] = &[
+    "bind",
+    "cloak",
+    "else",
+    "else-if",
+    "for",
+    "html",
+    "if",
+    "model",
+    "on",
+    "once",
+    "pre",
+    "show",
+    "slot",
+    "text",
+];
+
+/// Components provided by vue itself, in `kebab-case`.
+const COMPONENTS: &[&str] = &[
+    "component",
+    "keep-alive",
+    "slot",
+    "transition",
+    "transition-group",
+];
+
 const GLOBALS: &[&str] = &[
     // Values
     "Infinity",
@@ -901,3 +1241,208 @@ const GLOBALS: &[&str] = &[
 fn is_global(name: &str) -> bool {
     GLOBALS.into_iter().any(|&g| g == name)
 }
+
+#[cfg(all(test, feature = "runtime"))]
+mod tests {
+    use super::*;
+    use crate::runtime::HostDatabase;
+
+    /// Checks the first of the files, returning its rendered diagnostics.
+    pub(super) fn check_files(files: &[(&str, &str)]) -> Vec<String> {
+        let (db, file_ids) = HostDatabase::with_files(files);
+        check(&db, file_ids[0])
+    }
+
+    pub(super) fn check_component(text: &str) -> Vec<String> {
+        check_files(&[("src/App.vue", text)])
+    }
+
+    #[test]
+    fn test_directives() {
+        let component = r#"<template>
+  <div v-focus v-tooltip.top v-if="visible" v-click-outside v-unknown.mod></div>
+</template>
+<script>
+export default {
+  directives: { focus: {}, ClickOutside: {} },
+  data() { return { visible: true }; }
+};
+</script>"#;
+        let main = "import Vue from 'vue';\nVue.directive('tooltip', {});";
+        assert_eq!(check_files(&[("src/App.vue", component), ("src/main.js", main)]), vec![
+            "error(vue): [src/App.vue:2:61] directive `v-unknown` is not defined",
+        ]);
+        assert_eq!(check_component(component), vec![
+            "error(vue): [src/App.vue:2:16] directive `v-tooltip` is not defined",
+            "error(vue): [src/App.vue:2:61] directive `v-unknown` is not defined",
+        ]);
+    }
+
+    #[test]
+    fn test_extends() {
+        let component = r#"<template>
+  <p>{{ count }} {{ label }} {{ missing }}</p>
+</template>
+<script>
+import { Base, Labelled } from './base';
+export default { extends: Base };
+</script>"#;
+        let base = r#"export const Base = Vue.extend({ data() { return { count: 0 }; } });
+const Other = { props: ['label'] };
+export { Other as Labelled };"#;
+        assert_eq!(check_files(&[("src/App.vue", component), ("src/base.js", base)]), vec![
+            "error(vue): [src/App.vue:2:21] property `label` is not defined on the component",
+            "error(vue): [src/App.vue:2:33] property `missing` is not defined on the component",
+        ]);
+
+        let component = component.replace("extends: Base", "extends: Labelled");
+        assert_eq!(check_files(&[("src/App.vue", &component), ("src/base.js", base)]), vec![
+            "error(vue): [src/App.vue:2:9] property `count` is not defined on the component",
+            "error(vue): [src/App.vue:2:33] property `missing` is not defined on the component",
+        ]);
+    }
+
+    #[test]
+    fn test_extends_unknown() {
+        // The properties of an unknown `vm` aren't checked, but the rest of the component is
+        let component = r#"<template>
+  <p v-unknown>{{ count }}</p>
+</template>
+<script>
+import Base from 'some-package';
+export default {
+  extends: Base,
+  watch: { count() {} }
+};
+</script>"#;
+        assert_eq!(check_component(component), vec![
+            "error(vue): [src/App.vue:2:6] directive `v-unknown` is not defined",
+        ]);
+    }
+
+    #[test]
+    fn test_dynamic_arguments() {
+        let component = r#"<template>
+  <div :[attr]="value" @[event]="onEvent" :[key||missing]="value">
+    <my-list><template #[slot]>{{ value }}</template></my-list>
+    <p :[attr + suffix]="value" :['title']="value" :[attrName]="value"></p>
+  </div>
+</template>
+<script>
+export default {
+  data() { return { attr: 'title', event: 'click', key: 'id', slot: 'item', value: 1, attrName: 'title' }; },
+  methods: { onEvent() {} }
+};
+</script>"#;
+        let main = "import Vue from 'vue';\nVue.component('my-list', {});";
+        assert_eq!(check_files(&[("src/App.vue", component), ("src/main.js", main)]), vec![
+            "error(vue): [src/App.vue:4:9] dynamic arguments can't contain spaces",
+            "error(vue): [src/App.vue:4:34] dynamic arguments can't contain quotes",
+            "warn(pedantic): [src/App.vue:4:53] dynamic arguments shouldn't contain uppercase letters, because browsers lowercase attribute names in in-DOM templates",
+            "error(vue): [src/App.vue:2:50] property `missing` is not defined on the component",
+            "error(vue): [src/App.vue:4:17] property `suffix` is not defined on the component",
+        ]);
+    }
+
+    #[test]
+    fn test_handler_statements() {
+        let component = r#"<template>
+  <div>
+    <button @click="if (ok) go()" @focus="count++; go()" @blur="() => go(...args)" @keyup.enter="function () { go() }">Go</button>
+    <p v-if="">{{ go(1, ...args) }}</p>
+  </div>
+</template>
+<script>
+export default {
+  data() { return { ok: true, count: 0, args: [] }; },
+  methods: { go() {} }
+};
+</script>"#;
+        assert_eq!(check_component(component), vec![
+            "error(syntax): [src/App.vue:4:14] expected an expression",
+        ]);
+    }
+
+    #[test]
+    fn test_handler_scopes() {
+        let component = r#"<template>
+  <div>
+    <button @click="(x = step, ...rest) => go(x, rest, missing)" @focus="with (ok) go()">Go</button>
+    <button @click="while (count < limit) count++" @focus="for (let i = 0; i < count; i++) go(i, j)">Go</button>
+    <button @click="try { go() } catch (e) { go(e, other) }" @focus="switch (count) { case 1: let n = 1; go(n); break; default: go(k) }">Go</button>
+  </div>
+</template>
+<script>
+export default {
+  data() { return { ok: true, count: 0, step: 1, limit: 10 }; },
+  methods: { go(...args) {} }
+};
+</script>"#;
+        assert_eq!(check_component(component), vec![
+            "error(vue): [src/App.vue:3:56] property `missing` is not defined on the component",
+            "error(vue): [src/App.vue:4:98] property `j` is not defined on the component",
+            "error(vue): [src/App.vue:5:52] property `other` is not defined on the component",
+            "error(vue): [src/App.vue:5:132] property `k` is not defined on the component",
+        ]);
+    }
+
+    #[test]
+    fn test_pattern_defaults() {
+        let component = r#"<template>
+  <ul>
+    <li v-for="({ name = fallback, tags: [first, ...others] = [] }, i) in items" :key="i">
+      {{ name }} {{ first }} {{ others.length }} {{ missing }}
+    </li>
+    <child-component v-slot="{ item = unknown, rest: [head, ...tail] = [] }">
+      {{ item }} {{ head }} {{ tail.length }} {{ name }}
+    </child-component>
+  </ul>
+</template>
+<script>
+import ChildComponent from './ChildComponent.vue';
+export default {
+  components: { ChildComponent },
+  data() { return { items: [], fallback: '' }; }
+};
+</script>"#;
+        assert_eq!(check_component(component), vec![
+            "error(vue): [src/App.vue:4:53] property `missing` is not defined on the component",
+            "error(vue): [src/App.vue:6:39] property `unknown` is not defined on the component",
+            "error(vue): [src/App.vue:7:50] property `name` is not defined on the component",
+        ]);
+    }
+
+    /// Infers the types of a component's props, returning each as `name: type`.
+    fn prop_types(script: &str) -> Vec<String> {
+        let (program, _) = ts::Program::parse(script);
+        let props = get_object_property(component_options(&program).unwrap(), "props").unwrap();
+        let (props, _) = infer_props_types(props, TextUnit::default()).ok().unwrap();
+        props.properties.iter().map(|prop| format!("{}: {:?}", prop.ident, prop.type_)).collect()
+    }
+
+    #[test]
+    fn test_prop_types() {
+        let script = r#"export default {
+  props: {
+    user: { type: Object as PropType<User>, required: true },
+    ids: { type: Array as PropType<number[]>, required: true },
+    tags: { type: Array as PropType<Array<string>>, required: true },
+    mode: { type: String as PropType<'a' | 'b'>, required: true },
+    size: { type: [Number, String] as PropType<number | string | null>, required: true },
+    title: { type: String as Other<number>, required: true },
+    value: { type: null, required: true },
+    label: null
+  }
+}"#;
+        assert_eq!(prop_types(script), vec![
+            "user: Any",
+            "ids: Array(Number)",
+            "tags: Array(String)",
+            "mode: Any",
+            "size: Union([Number, String, Null])",
+            "title: String",
+            "value: Any",
+            "label: Any",
+        ]);
+    }
+}
@@ -0,0 +1,444 @@
+//! Checks the accessibility of templates and html documents (e.g. `<img>` without `alt`).
+//!
+//! Bindings to literals are checked like static attributes (e.g. `:tabindex="1"`), but other bindings
+//! can't be evaluated, so they're reported as `pedantic` warnings when they hide a value that should be checked.
+use super::Diagnostic;
+use super::model::{is_component_tag, tag_name};
+use super::options::suggest;
+use crate::ast::attribute_value;
+use code_grammar::{AstNode, SyntaxNode, SyntaxNodeExt, TextUnit};
+use typescript_grammar::ast as ts;
+use typescript_grammar::syntax_kind as ts_kind;
+use vue_grammar::ast as vue;
+use vue_grammar::syntax_kind::*;
+
+/// Elements which are interactive without a `role` or `tabindex`.
+const INTERACTIVE_ELEMENTS: &[&str] = &[
+    "a", "audio", "button", "details", "embed", "iframe", "input", "label", "option", "select", "summary", "textarea",
+    "video",
+];
+
+/// Elements which should be associated with a `<label>`.
+const FORM_CONTROLS: &[&str] = &["input", "select", "textarea"];
+
+/// Input types which don't need a label, because they're hidden or labelled by their value.
+const UNLABELLED_INPUTS: &[&str] = &["button", "hidden", "image", "reset", "submit"];
+
+const KEY_EVENTS: &[&str] = &["keydown", "keypress", "keyup"];
+
+/// The `aria-*` attributes defined by WAI-ARIA 1.1.
+const ARIA_ATTRIBUTES: &[&str] = &[
+    "aria-activedescendant",
+    "aria-atomic",
+    "aria-autocomplete",
+    "aria-busy",
+    "aria-checked",
+    "aria-colcount",
+    "aria-colindex",
+    "aria-colspan",
+    "aria-controls",
+    "aria-current",
+    "aria-describedby",
+    "aria-details",
+    "aria-disabled",
+    "aria-dropeffect",
+    "aria-errormessage",
+    "aria-expanded",
+    "aria-flowto",
+    "aria-grabbed",
+    "aria-haspopup",
+    "aria-hidden",
+    "aria-invalid",
+    "aria-keyshortcuts",
+    "aria-label",
+    "aria-labelledby",
+    "aria-level",
+    "aria-live",
+    "aria-modal",
+    "aria-multiline",
+    "aria-multiselectable",
+    "aria-orientation",
+    "aria-owns",
+    "aria-placeholder",
+    "aria-posinset",
+    "aria-pressed",
+    "aria-readonly",
+    "aria-relevant",
+    "aria-required",
+    "aria-roledescription",
+    "aria-rowcount",
+    "aria-rowindex",
+    "aria-rowspan",
+    "aria-selected",
+    "aria-setsize",
+    "aria-sort",
+    "aria-valuemax",
+    "aria-valuemin",
+    "aria-valuenow",
+    "aria-valuetext",
+];
+
+/// The non-abstract roles defined by WAI-ARIA 1.1.
+const ROLES: &[&str] = &[
+    "alert",
+    "alertdialog",
+    "application",
+    "article",
+    "banner",
+    "button",
+    "cell",
+    "checkbox",
+    "columnheader",
+    "combobox",
+    "complementary",
+    "contentinfo",
+    "definition",
+    "dialog",
+    "directory",
+    "document",
+    "feed",
+    "figure",
+    "form",
+    "grid",
+    "gridcell",
+    "group",
+    "heading",
+    "img",
+    "link",
+    "list",
+    "listbox",
+    "listitem",
+    "log",
+    "main",
+    "marquee",
+    "math",
+    "menu",
+    "menubar",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "navigation",
+    "none",
+    "note",
+    "option",
+    "presentation",
+    "progressbar",
+    "radio",
+    "radiogroup",
+    "region",
+    "row",
+    "rowgroup",
+    "rowheader",
+    "scrollbar",
+    "search",
+    "searchbox",
+    "separator",
+    "slider",
+    "spinbutton",
+    "status",
+    "switch",
+    "tab",
+    "table",
+    "tablist",
+    "tabpanel",
+    "term",
+    "textbox",
+    "timer",
+    "toolbar",
+    "tooltip",
+    "tree",
+    "treegrid",
+    "treeitem",
+];
+
+/// Checks the elements of a template, or of an html document (which has no components) if `vue` is false.
+pub(super) fn check_a11y(root: &SyntaxNode, vue: bool, results: &mut Vec<Diagnostic>) {
+    let elements = root.descendants().filter(|node| node.kind() == ELEMENT).collect::<Vec<_>>();
+    let labels = elements.iter()
+        .filter(|element| tag_name(element).map(|tag| tag.text().eq_ignore_ascii_case("label")).unwrap_or(false))
+        .map(|&element| Attributes::of(element))
+        .collect::<Vec<_>>();
+    let mut heading_level = None;
+    for element in elements {
+        let tag = match tag_name(element) {
+            Some(tag) => tag,
+            None => continue,
+        };
+        let pos = tag.range().start();
+        let tag = tag.text().as_str();
+        if vue && is_component_tag(tag) {
+            continue;
+        }
+        // N.B. the names of html elements are case-insensitive (e.g. `<IMG>` is an `<img>`)
+        let tag = tag.to_ascii_lowercase();
+        let tag = tag.as_str();
+        let attrs = Attributes::of(element);
+
+        // Check `aria-*` attributes, `role` and `tabindex`
+        for attr in &attrs.items {
+            if attr.name.starts_with("aria-") && !ARIA_ATTRIBUTES.contains(&attr.name) {
+                let message = match suggest(attr.name, ARIA_ATTRIBUTES.iter().cloned()) {
+                    Some(known) => format!("unknown aria attribute `{}`, did you mean `{}`?", attr.name, known),
+                    None => format!("unknown aria attribute `{}`", attr.name),
+                };
+                results.push(Diagnostic::warn("a11y", attr.pos, message));
+            }
+        }
+        match attrs.get("role") {
+            Some(Value::Static(role)) => {
+                for role in role.split_whitespace().filter(|role| !ROLES.contains(role)) {
+                    let message = match suggest(role, ROLES.iter().cloned()) {
+                        Some(known) => format!("unknown aria role `{}`, did you mean `{}`?", role, known),
+                        None => format!("unknown aria role `{}`", role),
+                    };
+                    results.push(Diagnostic::warn("a11y", attrs.pos("role"), message));
+                }
+            }
+            Some(Value::Bound(_)) => unevaluated(results, "role", attrs.pos("role")),
+            None => (),
+        }
+        match attrs.get("tabindex") {
+            Some(Value::Static(index)) => {
+                if index.trim().parse::<i32>().map(|index| index > 0).unwrap_or(false) {
+                    let message = "avoid a positive `tabindex`, because it changes the order of keyboard navigation";
+                    results.push(Diagnostic::warn("a11y", attrs.pos("tabindex"), message));
+                }
+            }
+            Some(Value::Bound(_)) => unevaluated(results, "tabindex", attrs.pos("tabindex")),
+            None => (),
+        }
+
+        // Check images have alternative text
+        if tag == "img" && attrs.get("alt").is_none() {
+            if attrs.spread {
+                unevaluated(results, "v-bind", attrs.spread_pos);
+            } else {
+                results.push(Diagnostic::warn("a11y", pos, "`<img>` elements must have an `alt` attribute (use `alt=\"\"` for decorative images)"));
+            }
+        }
+
+        // Check clickable elements can be used with a keyboard
+        if attrs.listeners.iter().any(|&event| event == "click") && !INTERACTIVE_ELEMENTS.contains(&tag) {
+            if attrs.get("role").is_none() {
+                let message = format!("`<{}>` elements with a `click` listener must have a `role` (e.g. `role=\"button\"`)", tag);
+                results.push(Diagnostic::warn("a11y", pos, message));
+            }
+            if !attrs.listeners.iter().any(|event| KEY_EVENTS.contains(event)) {
+                let message = format!("`<{}>` elements with a `click` listener must also have a keyboard listener (e.g. `@keydown.enter`)", tag);
+                results.push(Diagnostic::warn("a11y", pos, message));
+            }
+        }
+
+        // Check form controls have a label
+        let is_exempt = tag == "input" && match attrs.get("type") {
+            Some(Value::Static(type_)) => UNLABELLED_INPUTS.contains(&type_),
+            Some(Value::Bound(_)) => true,
+            None => false,
+        };
+        if FORM_CONTROLS.contains(&tag) && !is_exempt && !is_labelled(element, &attrs, &labels) {
+            if attrs.spread {
+                unevaluated(results, "v-bind", attrs.spread_pos);
+            } else {
+                let message = format!("`<{}>` elements must have a label (e.g. a `<label>` or `aria-label`)", tag);
+                results.push(Diagnostic::warn("a11y", pos, message));
+            }
+        }
+
+        // Check headings don't skip a level
+        let level = match tag {
+            "h1" => 1,
+            "h2" => 2,
+            "h3" => 3,
+            "h4" => 4,
+            "h5" => 5,
+            "h6" => 6,
+            _ => continue,
+        };
+        if let Some(previous) = heading_level {
+            if level > previous + 1 {
+                let message = format!("heading levels should only increase by one, but `<{}>` follows `<h{}>`", tag, previous);
+                results.push(Diagnostic::warn("a11y", pos, message));
+            }
+        }
+        heading_level = Some(level);
+    }
+}
+
+/// Returns true if a form control has an accessible name, or might have one from a binding.
+fn is_labelled(element: &SyntaxNode, attrs: &Attributes, labels: &[Attributes]) -> bool {
+    if ["aria-label", "aria-labelledby", "title"].iter().any(|&name| attrs.get(name).is_some()) {
+        return true;
+    }
+    let mut ancestor = element.parent();
+    while let Some(node) = ancestor {
+        if node.kind() == ELEMENT && tag_name(node).map(|tag| tag.text().eq_ignore_ascii_case("label")).unwrap_or(false) {
+            return true;
+        }
+        ancestor = node.parent();
+    }
+    match attrs.get("id") {
+        Some(Value::Static(id)) => labels.iter().any(|label| match label.get("for") {
+            Some(Value::Static(for_)) => for_ == id,
+            Some(Value::Bound(_)) => true,
+            None => false,
+        }),
+        Some(Value::Bound(_)) => labels.iter().any(|label| label.get("for").is_some()),
+        None => false,
+    }
+}
+
+fn unevaluated(results: &mut Vec<Diagnostic>, name: &str, pos: TextUnit) {
+    let message = format!("the accessibility of `{}` can't be checked, because it's bound to an expression", name);
+    results.push(Diagnostic::warn("pedantic", pos, message));
+}
+
+/// The value of an attribute, as far as it's known without evaluating the template.
+#[derive(Clone, Copy, Debug)]
+pub(super) enum Value<'a> {
+    /// A static attribute (e.g. `alt="..."`) or a binding to a literal (e.g. `:tabindex="0"`).
+    Static(&'a str),
+    /// A binding to any other expression (e.g. `:alt="caption"`).
+    Bound(&'a str),
+}
+
+pub(super) struct Attribute<'a> {
+    pub name: &'a str,
+    pub value: Value<'a>,
+    pub pos: TextUnit,
+}
+
+/// The attributes and listeners of an element.
+pub(super) struct Attributes<'a> {
+    pub items: Vec<Attribute<'a>>,
+    pub listeners: Vec<&'a str>,
+    /// Whether any attributes are bound with `v-bind="..."`, so the element may have any attribute.
+    pub spread: bool,
+    pub spread_pos: TextUnit,
+}
+
+impl<'a> Attributes<'a> {
+    pub fn of(element: &'a SyntaxNode) -> Attributes<'a> {
+        let mut attrs = Attributes { items: Vec::new(), listeners: Vec::new(), spread: false, spread_pos: TextUnit::default() };
+        for node in element.children() {
+            let pos = node.range().start();
+            match vue::Directive::cast(node) {
+                Some(directive) if directive.name() == "bind" => match directive.argument() {
+                    Some(key) => {
+                        if let Some(name) = key.name() {
+                            let expr = directive.value().unwrap_or_default();
+                            let value = literal(expr).map(Value::Static).unwrap_or(Value::Bound(expr));
+                            attrs.items.push(Attribute { name, value, pos });
+                        }
+                    }
+                    None => {
+                        attrs.spread = true;
+                        attrs.spread_pos = pos;
+                    }
+                },
+                Some(directive) if directive.name() == "on" => {
+                    if let Some(event) = directive.argument().and_then(vue::AttributeKey::name) {
+                        attrs.listeners.push(event);
+                    }
+                }
+                Some(_) => (),
+                None if node.kind() == ATTRIBUTE => {
+                    let name = match node.first_token() {
+                        Some(token) => token.text().as_str(),
+                        None => continue,
+                    };
+                    let value = match attribute_value(node) {
+                        Some(value) if value.kind() == QUOTED => {
+                            let text = value.text().as_str();
+                            &text[1 .. text.len() - 1]
+                        }
+                        Some(value) => value.text().as_str(),
+                        None => "",
+                    };
+                    attrs.items.push(Attribute { name, value: Value::Static(value), pos });
+                }
+                None => (),
+            }
+        }
+        attrs
+    }
+
+    /// Finds an attribute by name, which is case-insensitive like the names of html elements.
+    pub fn get(&self, name: &str) -> Option<Value<'a>> {
+        self.items.iter().find(|attr| attr.name.eq_ignore_ascii_case(name)).map(|attr| attr.value)
+    }
+
+    pub fn pos(&self, name: &str) -> TextUnit {
+        self.items.iter().find(|attr| attr.name.eq_ignore_ascii_case(name)).map(|attr| attr.pos).unwrap_or_default()
+    }
+}
+
+/// Evaluates a binding to a string or number literal (e.g. `'button'` or `-1`).
+pub(super) fn literal(expr: &str) -> Option<&str> {
+    let expr = expr.trim();
+    let (unsigned, negated) = match expr.strip_prefix('-') {
+        Some(rest) => (rest.trim_start(), true),
+        None => (expr, false),
+    };
+    // N.B. parse the value rather than matching quotes, so that e.g. `'a' + b + 'c'` isn't mistaken for a string
+    let (parsed, _) = ts::Expression::parse(unsigned);
+    if !parsed.errors().is_empty() {
+        return None;
+    }
+    let token = parsed.syntax.first_token()?;
+    if token.range() != parsed.syntax.range() {
+        return None;
+    }
+    match token.kind() {
+        ts_kind::NUMBER_LITERAL => Some(expr),
+        ts_kind::STRING_LITERAL | ts_kind::TEMPLATE_LITERAL if !negated && !token.text().contains("${") => {
+            Some(&unsigned[1 .. unsigned.len() - 1])
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_literal() {
+        assert_eq!(literal("'button'"), Some("button"));
+        assert_eq!(literal(" -1 "), Some("-1"));
+        assert_eq!(literal("`tab`"), Some("tab"));
+        assert_eq!(literal("`${role}`"), None);
+        assert_eq!(literal("role"), None);
+        assert_eq!(literal("\"link\""), Some("link"));
+        assert_eq!(literal("'a' + b + 'c'"), None);
+        assert_eq!(literal("'a' + 'b'"), None);
+        assert_eq!(literal("1 - 2"), None);
+        assert_eq!(literal("-'1'"), None);
+    }
+}
+
+#[cfg(all(test, feature = "runtime"))]
+mod runtime_tests {
+    use super::super::tests::check_files;
+
+    #[test]
+    fn test_html_documents() {
+        let document = r#"<!DOCTYPE html>
+<html>
+  <body>
+    <img src="logo.png">
+    <div tabindex="2">Skip</div>
+    <IMG SRC="banner.png">
+    <IMG SRC="spacer.png" ALT="">
+    <LABEL>Name <INPUT name="name"></LABEL>
+    <INPUT name="email">
+  </body>
+</html>
+"#;
+        assert_eq!(check_files(&[("public/index.html", document)]), vec![
+            "warn(a11y): [public/index.html:4:6] `<img>` elements must have an `alt` attribute (use `alt=\"\"` for decorative images)",
+            "warn(a11y): [public/index.html:5:10] avoid a positive `tabindex`, because it changes the order of keyboard navigation",
+            "warn(a11y): [public/index.html:6:6] `<img>` elements must have an `alt` attribute (use `alt=\"\"` for decorative images)",
+            "warn(a11y): [public/index.html:9:6] `<input>` elements must have a label (e.g. a `<label>` or `aria-label`)",
+        ]);
+    }
+}
@@ -0,0 +1,245 @@
+//! Checks the arguments passed to the component's methods by template expressions and event listeners.
+use super::{Diagnostic, TemplateCode};
+use super::model::{is_component_tag, tag_name};
+use crate::VueDatabase;
+use crate::app::{rendered_components, script_source, string_value};
+use code_analysis::{original_range, FileId, SourceId};
+use code_grammar::{AstNode, SyntaxElement, SyntaxNodeExt, TextRange, WalkEvent};
+use typescript_analysis::ty::{FunctionSig, InterfaceTy, Ty, TypeOf};
+use typescript_grammar::ast as ts;
+use typescript_grammar::syntax_kind::SPREAD_ELEMENT;
+use vue_grammar::ast as vue;
+use vue_grammar::syntax_kind::ATTRIBUTE_LISTENER;
+
+/// Checks the number of arguments in calls to the component's methods (e.g. `{{ format(date) }}`).
+pub(super) fn check_calls(
+    db: &impl VueDatabase,
+    expressions: &[(TemplateCode, SourceId)],
+    vm: &InterfaceTy,
+    is_declared: impl Fn(&str, TextRange) -> bool,
+    results: &mut Vec<Diagnostic>,
+) {
+    for &(ref code, src_id) in expressions {
+        for call in code.syntax().descendants().filter_map(ts::CallExpression::cast) {
+            let callee = match call.callee().and_then(ts::Identifier::downcast) {
+                Some(callee) => callee,
+                None => continue,
+            };
+            let name = callee.name();
+            let range = match original_range(db, src_id, callee.syntax.range()) {
+                Some(original) => original.range,
+                None => continue,
+            };
+            let sig = match method(vm, name) {
+                Some(sig) if !is_declared(name, range) => sig,
+                _ => continue,
+            };
+            if call.syntax.children().any(|node| node.kind() == SPREAD_ELEMENT) {
+                continue; // N.B. e.g. `save(...args)`
+            }
+            let args = call.arguments().count();
+            let pos = range.start();
+            if args < sig.required {
+                let message = format!("method `{}` expects at least {} {}, but got {}", name, sig.required, plural(sig.required), args);
+                results.push(Diagnostic::error("vue", pos, message));
+            } else if args > sig.inputs.len() && !sig.variadic {
+                let message = format!("method `{}` expects at most {} {}, but got {}", name, sig.inputs.len(), plural(sig.inputs.len()), args);
+                results.push(Diagnostic::error("vue", pos, message));
+            }
+        }
+    }
+}
+
+/// Checks the handlers of event listeners, which are called with `$event` (e.g. `@click="save"`).
+pub(super) fn check_listeners(
+    db: &impl VueDatabase,
+    file_id: FileId,
+    template: &vue::Template,
+    vm: &InterfaceTy,
+    is_declared: impl Fn(&str, TextRange) -> bool,
+    results: &mut Vec<Diagnostic>,
+) {
+    let rendered = rendered_components(db, file_id, &template.syntax);
+    let listeners = template.syntax.descendants()
+        .filter(|node| node.kind() == ATTRIBUTE_LISTENER)
+        .filter_map(vue::Directive::cast);
+    for listener in listeners {
+        let (code, expr_src_id) = match super::directive_code(db, db.file_source(file_id), listener) {
+            Some(code) => code,
+            None => continue,
+        };
+        let original = |range: TextRange| original_range(db, expr_src_id, range).map(|original| original.range);
+
+        // A method handler (e.g. `@click="save"`) is called with only `$event`
+        if let TemplateCode::Expression(expr) = &code {
+            if let Some(ident) = ts::Identifier::downcast(&**expr) {
+                let name = ident.name();
+                let range = match original(ident.syntax.range()) {
+                    Some(range) => range,
+                    None => continue,
+                };
+                let sig = match method(vm, name) {
+                    Some(sig) if !is_declared(name, range) => sig,
+                    _ => continue,
+                };
+                if sig.required > 1 {
+                    let message = format!(
+                        "method `{}` expects at least {} arguments, but a handler is only called with `$event`",
+                        name,
+                        sig.required,
+                    );
+                    results.push(Diagnostic::error("vue", range.start(), message));
+                }
+                continue;
+            }
+        }
+
+        // N.B. only a free `$event` refers to the payload (e.g. not `data.$event` or `$event => save($event)`)
+        let event = match code.captures().refs.into_iter().find(|&(name, _)| name == "$event") {
+            Some((_, event)) => event,
+            None => continue,
+        };
+        let event_range = match original(event.syntax.range()) {
+            Some(range) => range,
+            None => continue,
+        };
+        let name = listener.argument().and_then(vue::AttributeKey::name);
+        let modifiers = listener.modifiers();
+        let element = listener.syntax.parent();
+        let child = rendered.iter()
+            .find(|(node, _)| Some(node.range()) == element.map(|element| element.range()))
+            .map(|&(_, child)| child);
+        let tag = element.and_then(tag_name);
+        let payload = match (child, name) {
+            _ if modifiers.iter().any(|&(modifier, _)| modifier == "native") => Ty::Hint(TypeOf::Object),
+            (Some(child), Some(name)) => emitted_payload(db, child, name),
+            (None, _) if tag.map(|tag| !is_component_tag(tag.text())).unwrap_or(false) => Ty::Hint(TypeOf::Object),
+            _ => Ty::Any,
+        };
+        if let Ty::Undefined = payload {
+            let message = format!("`$event` is always `undefined`, because `{}` is emitted without a payload", name.unwrap_or_default());
+            results.push(Diagnostic::warn("vue", event_range.start(), message));
+        }
+    }
+}
+
+/// Infers the type of `$event` for an event emitted by a component with `this.$emit(...)`.
+fn emitted_payload(db: &impl VueDatabase, file_id: FileId, event: &str) -> Ty {
+    // N.B. events emitted in the component's template aren't inferred yet
+    let component = db.vue_ast(db.file_source(file_id));
+    if component.template().map(|template| template.syntax.preorder_with_tokens().any(|event| match event {
+        WalkEvent::Enter(SyntaxElement::Token(token)) => token.text().contains("$emit"),
+        _ => false,
+    })).unwrap_or(true) {
+        return Ty::Any;
+    }
+    let program = match script_source(db, file_id) {
+        Some(src_id) => db.typescript_ast(src_id),
+        None => return Ty::Any,
+    };
+    let mut emitted = false;
+    for call in program.syntax.descendants().filter_map(ts::CallExpression::cast) {
+        let is_emit = call.callee()
+            .and_then(ts::MemberExpression::downcast)
+            .and_then(|member| member.property())
+            .and_then(ts::Identifier::downcast)
+            .map(|property| property.name() == "$emit")
+            .unwrap_or(false);
+        if !is_emit {
+            continue;
+        }
+        let mut args = call.arguments();
+        match args.next().and_then(string_value) {
+            Some(name) if name == event => (),
+            Some(_) => continue,
+            None => return Ty::Any, // N.B. the event's name isn't known
+        }
+        if args.next().is_some() || call.syntax.children().any(|node| node.kind() == SPREAD_ELEMENT) {
+            return Ty::Any;
+        }
+        emitted = true;
+    }
+    if emitted { Ty::Undefined } else { Ty::Any }
+}
+
+fn method<'a>(vm: &'a InterfaceTy, name: &str) -> Option<&'a FunctionSig> {
+    vm.properties.iter().find(|prop| prop.ident == name).and_then(|prop| prop.type_.as_function())
+}
+
+fn plural(count: usize) -> &'static str {
+    if count == 1 { "argument" } else { "arguments" }
+}
+
+#[cfg(all(test, feature = "runtime"))]
+mod tests {
+    use super::super::tests::{check_component, check_files};
+
+    #[test]
+    fn test_arity() {
+        let component = r#"<template>
+  <p>{{ format() }} {{ format(1, 2) }} {{ format(1, 2, 3) }} {{ join() }} {{ join(1, 2, 3) }}</p>
+</template>
+<script>
+export default {
+  methods: {
+    format(value, unit = 'px') { return value + unit; },
+    join(first, ...rest) { return [first].concat(rest); }
+  }
+};
+</script>"#;
+        assert_eq!(check_component(component), vec![
+            "error(vue): [src/App.vue:2:9] method `format` expects at least 1 argument, but got 0",
+            "error(vue): [src/App.vue:2:43] method `format` expects at most 2 arguments, but got 3",
+            "error(vue): [src/App.vue:2:65] method `join` expects at least 1 argument, but got 0",
+        ]);
+    }
+
+    #[test]
+    fn test_handlers() {
+        let component = r#"<template>
+  <div>
+    <button @click="save" aria-label="save">Save</button>
+    <button @click="move" aria-label="move">Move</button>
+    <button @click="format" aria-label="format">Format</button>
+    <button @click="  move  " aria-label="move">Move</button>
+  </div>
+</template>
+<script>
+export default {
+  methods: {
+    save(event) {},
+    move(x, y) {},
+    format(value, unit = 'px') {}
+  }
+};
+</script>"#;
+        assert_eq!(check_component(component), vec![
+            "error(vue): [src/App.vue:4:21] method `move` expects at least 2 arguments, but a handler is only called with `$event`",
+            "error(vue): [src/App.vue:6:23] method `move` expects at least 2 arguments, but a handler is only called with `$event`",
+        ]);
+    }
+
+    #[test]
+    fn test_event_payload() {
+        let component = r#"<template>
+  <child-component @close="onClose($event)" @open="onOpen(data.$event)" @toggle="items.map($event => $event)" @close.native="if (open) onClose($event)"></child-component>
+</template>
+<script>
+import ChildComponent from './Child.vue';
+export default {
+  components: { ChildComponent },
+  data() { return { data: {}, items: [], open: true }; },
+  methods: { onClose(value) {}, onOpen(value) {} }
+};
+</script>"#;
+        let child = r#"<template><p></p></template>
+<script>
+export default {
+  methods: { close() { this.$emit('close'); }, open() { this.$emit('open'); }, toggle() { this.$emit('toggle'); } }
+};
+</script>"#;
+        assert_eq!(check_files(&[("src/App.vue", component), ("src/Child.vue", child)]), vec![
+            "warn(vue): [src/App.vue:2:36] `$event` is always `undefined`, because `close` is emitted without a payload",
+        ]);
+    }
+}
@@ -0,0 +1,391 @@
+//! Checks that the elements of templates and html documents follow the HTML5 content models
+//! (e.g. `<div>` can't be a child of `<p>`), and that native elements and attributes are known.
+//!
+//! Components and unknown elements are skipped, because their content models aren't known.
+use super::Diagnostic;
+use super::a11y::{Attributes, Value};
+use super::model::{is_component_tag, tag_name};
+use super::options::suggest;
+use code_grammar::{AstNode, SyntaxElement, SyntaxNode, SyntaxNodeExt};
+use html_analysis::schema::{self, Content, ElementSchema, FLOW, PHRASING, SCRIPT_SUPPORTING};
+use vue_grammar::ast as vue;
+use vue_grammar::syntax_kind::*;
+
+/// Elements which vue doesn't render, so that their content is rendered in their parent element instead.
+const VUE_WRAPPERS: &[&str] = &["template", "slot"];
+
+/// Attributes which vue handles itself, instead of rendering them on the element.
+const VUE_ATTRIBUTES: &[&str] = &["key", "ref", "scope", "slot-scope"];
+
+/// Elements whose content isn't html, so it isn't checked.
+const FOREIGN_ELEMENTS: &[&str] = &["math", "svg"];
+
+/// Checks the elements of an html document.
+pub(super) fn check_document(root: &SyntaxNode, results: &mut Vec<Diagnostic>) {
+    let context = Context { vue: false, is_component: Some(&|_| false) };
+    context.check(root, results);
+}
+
+/// Checks the elements of a vue template, given whether a tag is a registered component.
+///
+/// If the registered components aren't known (e.g. the script has syntax errors), unknown elements aren't reported.
+pub(super) fn check_template(root: &SyntaxNode, is_component: Option<&dyn Fn(&str) -> bool>, results: &mut Vec<Diagnostic>) {
+    let context = Context { vue: true, is_component };
+    context.check(root, results);
+}
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Native(&'static ElementSchema),
+    /// An element which isn't rendered itself (e.g. `<template v-if="...">` in a vue template).
+    Wrapper,
+    /// A component or custom element.
+    Component,
+    Unknown,
+}
+
+struct Context<'a> {
+    vue: bool,
+    is_component: Option<&'a dyn Fn(&str) -> bool>,
+}
+
+impl Context<'_> {
+    fn check(&self, root: &SyntaxNode, results: &mut Vec<Diagnostic>) {
+        for node in root.descendants() {
+            match node.kind() {
+                ELEMENT => self.check_element(node, results),
+                VOID_END_TAG => {
+                    if let Some(tag) = tag_name(node) {
+                        let message = format!("`<{}>` is a void element, so it can't have an end tag", tag.text());
+                        results.push(Diagnostic::error("html", node.range().start(), message));
+                    }
+                }
+                STRAY_END_TAG => {
+                    let message = "`</p>` doesn't close a `<p>`, so browsers treat it as an empty `<p>`";
+                    results.push(Diagnostic::error("html", node.range().start(), message));
+                }
+                IMPLIED_END_TAG => self.check_implied_end_tag(node, results),
+                _ => (),
+            }
+        }
+    }
+
+    fn kind(&self, element: &SyntaxNode) -> Kind {
+        let tag = match tag_name(element) {
+            Some(tag) => tag,
+            None => return Kind::Unknown,
+        };
+        let tag = tag.text().as_str();
+        if self.vue && VUE_WRAPPERS.contains(&tag) {
+            Kind::Wrapper
+        } else if is_component_tag(tag) || self.is_component.map(|is_component| is_component(tag)).unwrap_or(false) {
+            Kind::Component
+        } else {
+            schema::element(tag).map(Kind::Native).unwrap_or(Kind::Unknown)
+        }
+    }
+
+    fn check_element(&self, element: &SyntaxNode, results: &mut Vec<Diagnostic>) {
+        let tag = match tag_name(element) {
+            Some(tag) => tag,
+            None => return,
+        };
+        let pos = tag.range().start();
+        let tag = tag.text().as_str();
+        let ancestors = element.ancestors().skip(1).filter(|node| node.kind() == ELEMENT).collect::<Vec<_>>();
+        if ancestors.iter().any(|node| tag_name(node).map(|tag| FOREIGN_ELEMENTS.contains(&tag.text().as_str())).unwrap_or(false)) {
+            return;
+        }
+        let schema = match self.kind(element) {
+            Kind::Native(schema) => schema,
+            Kind::Wrapper | Kind::Component => return,
+            Kind::Unknown if self.is_component.is_none() => return,
+            Kind::Unknown => {
+                let known = if self.vue { "an html element or a registered component" } else { "an html element" };
+                let message = match suggest(&tag.to_ascii_lowercase(), schema::ELEMENTS.iter().map(|element| element.name)) {
+                    Some(name) => format!("`<{}>` isn't {}, did you mean `<{}>`?", tag, known, name),
+                    None => format!("`<{}>` isn't {}", tag, known),
+                };
+                results.push(Diagnostic::warn("html", pos, message));
+                return;
+            }
+        };
+        self.check_attributes(element, schema, results);
+
+        // Check text is allowed in the element
+        if !allows_text(schema.content) {
+            let text = element.children_with_tokens().find_map(|syn| match syn {
+                SyntaxElement::Token(token) if token.kind() == MUSTACHE => Some(token),
+                SyntaxElement::Token(token) if token.kind() == TEXT && !token.text().trim().is_empty() => Some(token),
+                _ => None,
+            });
+            if let Some(text) = text {
+                let message = format!("`<{}>` can't contain text", schema.name);
+                results.push(Diagnostic::warn("html", text.range().start(), message));
+            }
+        }
+
+        // Check the element is allowed in its parent
+        let attrs = Attributes::of(element);
+        let categories = schema.categories_with(|name| match attrs.get(name) {
+            Some(Value::Static(value)) => Some(value),
+            Some(Value::Bound(_)) => Some(""),
+            None => None,
+        });
+        // N.B. wrappers aren't rendered, so they're skipped when looking for the parent element
+        let parents = ancestors.iter().map(|node| self.kind(node)).filter(|kind| match kind {
+            Kind::Wrapper => false,
+            _ => true,
+        });
+        if let Some(Kind::Native(parent)) = parents.clone().next() {
+            if !schema.parents.is_empty() {
+                if !schema.parents.contains(&parent.name) {
+                    let message = format!("`<{}>` must be a child of {}", schema.name, tags(schema.parents));
+                    results.push(Diagnostic::warn("html", pos, message));
+                }
+            } else {
+                // N.B. a transparent element has the content model of its parent (e.g. `<a>` in a `<p>`)
+                let model = parents
+                    .clone()
+                    .map(|kind| match kind {
+                        Kind::Native(schema) => Some(schema),
+                        _ => None,
+                    })
+                    .find(|model| model.map(|model| model.content != Content::Transparent).unwrap_or(true))
+                    .and_then(|model| model);
+                let allowed = match model.map(|model| model.content) {
+                    Some(Content::Categories(allowed)) => categories & allowed != 0,
+                    Some(Content::Elements(names)) => names.contains(&schema.name) || categories & SCRIPT_SUPPORTING != 0,
+                    Some(Content::Void) | Some(Content::Nothing) | Some(Content::Text) => false,
+                    Some(Content::Transparent) | Some(Content::Any) | None => true,
+                };
+                if let (false, Some(model)) = (allowed, model) {
+                    let contents = match model.content {
+                        Content::Categories(allowed) => format!("can only contain {}", schema::describe(allowed)),
+                        Content::Elements(names) => format!("can only contain {}", tags(names)),
+                        Content::Text => "can only contain text".into(),
+                        _ => "can't have any content".into(),
+                    };
+                    let message = format!("`<{}>` isn't allowed in `<{}>`, which {}", schema.name, model.name, contents);
+                    results.push(Diagnostic::warn("html", pos, message));
+                }
+            }
+        }
+
+        // Check the element isn't excluded by any of its ancestors (e.g. a `<button>` in an `<a>`)
+        for ancestor in parents {
+            let ancestor = match ancestor {
+                Kind::Native(schema) => schema,
+                _ => break,
+            };
+            if ancestor.excluded_elements.contains(&schema.name) {
+                let message = format!("`<{}>` can't be inside `<{}>`", schema.name, ancestor.name);
+                results.push(Diagnostic::warn("html", pos, message));
+                break;
+            } else if ancestor.excludes & categories != 0 {
+                let message = format!(
+                    "`<{}>` can't be inside `<{}>`, which can't contain {}",
+                    schema.name,
+                    ancestor.name,
+                    schema::describe(ancestor.excludes & categories),
+                );
+                results.push(Diagnostic::warn("html", pos, message));
+                break;
+            }
+        }
+    }
+
+    /// Checks whether a `<p>` is implicitly closed by an element which isn't allowed in it (e.g. the `<div>` in
+    /// `<p><div></div></p>`), which would otherwise be a child of the `<p>`.
+    ///
+    /// N.B. a `<p>` closed by the next `<p>` is the usual way to omit its end tag, so it isn't reported.
+    fn check_implied_end_tag(&self, end_tag: &SyntaxNode, results: &mut Vec<Diagnostic>) {
+        let paragraph = match end_tag.parent() {
+            Some(parent) if tag_name(parent).map(|tag| tag.text().eq_ignore_ascii_case("p")).unwrap_or(false) => parent,
+            _ => return,
+        };
+        let next = match paragraph.next_sibling_or_token() {
+            Some(SyntaxElement::Node(node)) if node.kind() == ELEMENT => node,
+            _ => return,
+        };
+        let schema = match self.kind(next) {
+            Kind::Native(schema) if schema.name != "p" => schema,
+            _ => return,
+        };
+        if let Some(tag) = tag_name(next) {
+            let message = format!("`<{}>` isn't allowed in `<p>`, which can only contain phrasing content", schema.name);
+            results.push(Diagnostic::warn("html", tag.range().start(), message));
+        }
+    }
+
+    fn check_attributes(&self, element: &SyntaxNode, schema: &ElementSchema, results: &mut Vec<Diagnostic>) {
+        for node in element.children() {
+            let (name, pos) = match vue::Directive::cast(node) {
+                Some(directive) if directive.name() == "bind" => {
+                    // N.B. bindings with `.prop` set a DOM property instead of an attribute (e.g. `:textContent.prop`)
+                    if directive.modifiers().iter().any(|&(modifier, _)| modifier == "prop") {
+                        continue;
+                    }
+                    match directive.argument() {
+                        Some(key) => match key.name() {
+                            Some(name) => (name, key.range().start()),
+                            None => continue,
+                        },
+                        None => continue,
+                    }
+                }
+                Some(_) => continue,
+                // N.B. attributes with a namespace aren't checked (e.g. `xlink:href`)
+                None if node.kind() == ATTRIBUTE && node.children().any(|child| child.kind() == ATTRIBUTE_KEY) => continue,
+                None if node.kind() == ATTRIBUTE => match node.first_token() {
+                    Some(token) => (token.text().as_str(), token.range().start()),
+                    None => continue,
+                },
+                None => continue,
+            };
+            let name = name.to_ascii_lowercase();
+            if schema.has_attribute(&name) || (self.vue && VUE_ATTRIBUTES.contains(&name.as_str())) {
+                continue;
+            }
+            let candidates = schema::GLOBAL_ATTRIBUTES.iter().chain(schema.attributes).cloned();
+            let message = match suggest(&name, candidates) {
+                Some(known) => format!("unknown attribute `{}` on `<{}>`, did you mean `{}`?", name, schema.name, known),
+                None => format!("unknown attribute `{}` on `<{}>`", name, schema.name),
+            };
+            results.push(Diagnostic::warn("html", pos, message));
+        }
+    }
+}
+
+fn allows_text(content: Content) -> bool {
+    match content {
+        Content::Categories(categories) => categories & (FLOW | PHRASING) != 0,
+        Content::Text | Content::Transparent | Content::Any => true,
+        Content::Void | Content::Nothing | Content::Elements(_) => false,
+    }
+}
+
+/// Formats a list of tag names for messages (e.g. "`<ol>`, `<ul>` or `<menu>`").
+fn tags(names: &[&str]) -> String {
+    let tags = names.iter().map(|name| format!("`<{}>`", name)).collect::<Vec<_>>();
+    match tags.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use html_grammar::ast as html;
+
+    fn check(text: &str) -> Vec<String> {
+        let (document, _) = html::Document::parse(text);
+        assert!(document.errors().is_empty(), "{:?}", document.errors());
+        let mut results = Vec::new();
+        check_document(&document.syntax, &mut results);
+        results.into_iter().map(|diag| diag.message).collect()
+    }
+
+    #[test]
+    fn test_content_models() {
+        assert_eq!(check("<ul><li>a</li></ul><p><a href='#'>b</a><br></p>"), Vec::<String>::new());
+        assert_eq!(check("<p><div></div></p>"), vec![
+            "`<div>` isn't allowed in `<p>`, which can only contain phrasing content",
+            "`</p>` doesn't close a `<p>`, so browsers treat it as an empty `<p>`",
+        ]);
+        assert_eq!(check("<span><div></div></span>"), vec![
+            "`<div>` isn't allowed in `<span>`, which can only contain phrasing content",
+        ]);
+        assert_eq!(check("<div><p>a<p>b<ul></ul></div>"), vec![
+            "`<ul>` isn't allowed in `<p>`, which can only contain phrasing content",
+        ]);
+        assert_eq!(check("<div><li></li></div>"), vec!["`<li>` must be a child of `<ol>`, `<ul>` or `<menu>`"]);
+        assert_eq!(check("<ul>text<span></span></ul>"), vec![
+            "`<ul>` can't contain text",
+            "`<span>` isn't allowed in `<ul>`, which can only contain `<li>`",
+        ]);
+        assert_eq!(check("<p><a href='#'><div></div></a></p>"), vec![
+            "`<div>` isn't allowed in `<p>`, which can only contain phrasing content",
+        ]);
+        assert_eq!(check("<a href='#'><span><button></button></span></a>"), vec![
+            "`<button>` can't be inside `<a>`, which can't contain interactive content",
+        ]);
+        assert_eq!(check("<form><div><form></form></div></form>"), vec!["`<form>` can't be inside `<form>`"]);
+        assert_eq!(check("<p>a<br></br></p>"), vec!["`<br>` is a void element, so it can't have an end tag"]);
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(check("<sectoin></sectoin><my-element></my-element><svg><path d=''></path></svg>"), vec![
+            "`<sectoin>` isn't an html element, did you mean `<section>`?",
+        ]);
+        assert_eq!(check("<a hreff='#' data-id='1' onclick='' aria-label='a'></a>"), vec![
+            "unknown attribute `hreff` on `<a>`, did you mean `href`?",
+        ]);
+    }
+}
+
+#[cfg(all(test, feature = "runtime"))]
+mod runtime_tests {
+    use super::super::tests::check_component;
+
+    #[test]
+    fn test_templates() {
+        let component = r#"<template>
+  <form v-on:submit.prevent="save" @reset="save" :action="url" v-focus:top.lazy>
+    <ul><span></span></ul>
+    <frobnicate></frobnicate>
+    <child-component></child-component>
+  </form>
+</template>
+<script>
+import ChildComponent from './ChildComponent.vue';
+export default {
+  components: { ChildComponent },
+  directives: { focus: {} },
+  data() { return { url: '' }; },
+  methods: { save() {} }
+}
+</script>
+"#;
+        assert_eq!(check_component(component), vec![
+            "warn(html): [src/App.vue:3:10] `<span>` isn't allowed in `<ul>`, which can only contain `<li>`",
+            "warn(html): [src/App.vue:4:6] `<frobnicate>` isn't an html element or a registered component",
+        ]);
+    }
+
+    #[test]
+    fn test_script_errors() {
+        let component = r#"<template>
+  <ul><span></span><frobnicate></frobnicate></ul>
+</template>
+<script>
+export default {
+</script>
+"#;
+        let results = check_component(component);
+        assert!(results.contains(&"warn(html): [src/App.vue:2:8] `<span>` isn't allowed in `<ul>`, which can only contain `<li>`".into()), "{:?}", results);
+        assert!(!results.iter().any(|result| result.contains("frobnicate")), "{:?}", results);
+    }
+
+    #[test]
+    fn test_stray_end_tags() {
+        let component = r#"<template>
+  <section>
+    <p>text<div>block</div></p>
+    <span>{{ missing }}</span>
+  </section>
+</template>
+<script>
+export default {}
+</script>
+"#;
+        assert_eq!(check_component(component), vec![
+            "warn(html): [src/App.vue:3:13] `<div>` isn't allowed in `<p>`, which can only contain phrasing content",
+            "error(html): [src/App.vue:3:28] `</p>` doesn't close a `<p>`, so browsers treat it as an empty `<p>`",
+            "error(vue): [src/App.vue:4:14] property `missing` is not defined on the component",
+        ]);
+    }
+}
@@ -0,0 +1,45 @@
+//! Checks that imported names are exported by the imported module.
+use super::Diagnostic;
+use crate::VueDatabase;
+use crate::module::{is_exported, module_specifier};
+use code_analysis::FileId;
+use code_grammar::{AstNode, TextUnit};
+use typescript_grammar::ast as ts;
+
+pub(super) fn check_imports(
+    db: &impl VueDatabase,
+    file_id: FileId,
+    program: &ts::Program,
+    base: TextUnit,
+    results: &mut Vec<Diagnostic>,
+) {
+    for import in program.syntax.children().filter_map(ts::ImportDeclaration::cast) {
+        let specifier = match import.source().and_then(module_specifier) {
+            Some(specifier) => specifier,
+            None => continue,
+        };
+
+        // N.B. unresolved imports may be provided by the bundler, so aren't reported here
+        let target = match db.resolve_import(file_id, specifier.clone()) {
+            Some(target) => target,
+            None => continue,
+        };
+        for spec in import.specifiers() {
+            let (name, ident) = match spec.kind() {
+                ts::ModuleSpecifierKind::ImportDefaultSpecifier(default) => ("default", default.local()),
+                ts::ModuleSpecifierKind::ImportSpecifier(named) => (named.imported().name(), named.imported()),
+                ts::ModuleSpecifierKind::ImportNamespaceSpecifier(_) | ts::ModuleSpecifierKind::ExportSpecifier(_) => continue,
+            };
+            if is_exported(db, target, name) != Some(false) {
+                continue;
+            }
+            let pos = base + ident.syntax.range().start();
+            let message = if name == "default" {
+                format!("module `{}` has no default export", specifier)
+            } else {
+                format!("module `{}` has no exported member `{}`", specifier, name)
+            };
+            results.push(Diagnostic::error("correctness", pos, message));
+        }
+    }
+}
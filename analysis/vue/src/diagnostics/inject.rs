@@ -0,0 +1,162 @@
+//! Checks that the keys injected by a component are provided by one of its ancestors.
+use super::Diagnostic;
+use crate::VueDatabase;
+use crate::app::{get_object_property, string_value};
+use code_analysis::FileId;
+use code_grammar::{AstNode, SmolStr, TextUnit};
+use typescript_analysis::ty::infer_property_name;
+use typescript_grammar::ast as ts;
+use rustc_hash::FxHashSet;
+
+/// A property injected into the `vm` by the `inject` option.
+pub(super) struct Injection {
+    /// The name of the property on the `vm`.
+    pub name: SmolStr,
+    /// The key provided by the ancestor (e.g. `from` in `inject: { foo: { from: 'bar' } }`).
+    pub key: SmolStr,
+    /// The offset of the injection within the script.
+    pub pos: TextUnit,
+}
+
+/// Finds the properties injected by `inject: ['foo']` or `inject: { foo: 'bar' }`.
+pub(super) fn injections(vue_options: &ts::ObjectExpression) -> Vec<Injection> {
+    let mut injections = Vec::new();
+    let inject = match get_object_property(vue_options, "inject") {
+        Some(inject) => inject,
+        None => return injections,
+    };
+    match inject.kind() {
+        ts::ExpressionKind::ArrayExpression(arr) => {
+            for el in arr.elements() {
+                if let Some(name) = string_value(el) {
+                    injections.push(Injection { key: name.clone(), name, pos: el.syntax.range().start() });
+                }
+            }
+        }
+        ts::ExpressionKind::ObjectExpression(obj) => {
+            for prop in obj.properties() {
+                let name = match infer_property_name(prop) {
+                    Some(name) => name,
+                    None => continue,
+                };
+                let key = prop.value()
+                    .and_then(|value| match ts::ObjectExpression::downcast(value) {
+                        Some(options) => get_object_property(options, "from").and_then(string_value),
+                        None => string_value(value),
+                    })
+                    .unwrap_or_else(|| name.clone());
+                injections.push(Injection { name, key, pos: prop.syntax.range().start() });
+            }
+        }
+        _ => (),
+    }
+    injections
+}
+
+pub(super) fn check_injections(
+    db: &impl VueDatabase,
+    file_id: FileId,
+    vue_options: &ts::ObjectExpression,
+    base: TextUnit,
+    results: &mut Vec<Diagnostic>,
+) {
+    for injection in injections(vue_options) {
+        if is_provided(db, file_id, &injection.key) == Some(false) {
+            let message = format!("no ancestor component provides `{}`", injection.key);
+            results.push(Diagnostic::warn("vue", base + injection.pos, message));
+        }
+    }
+}
+
+/// Returns whether any ancestor of a component provides a key.
+///
+/// Returns `None` if some ancestors can't be determined statically (e.g. a component which is only
+/// rendered by the router) or some provided keys can't be determined.
+fn is_provided(db: &impl VueDatabase, file_id: FileId, key: &str) -> Option<bool> {
+    let graph = db.component_graph(db.file_source_root(file_id));
+    let mut visited = FxHashSet::default();
+    let mut stack = vec![file_id];
+    let mut complete = true;
+    while let Some(component) = stack.pop() {
+        if !visited.insert(component) {
+            continue;
+        }
+
+        // N.B. a component's own `provide` is only visible to its descendants
+        if component != file_id {
+            match db.component_provides(component).provides(key) {
+                Some(true) => return Some(true),
+                Some(false) => (),
+                None => complete = false,
+            }
+        }
+        let root = graph.roots.get(&component);
+        if let Some(root) = root {
+            match root.provides(key) {
+                Some(true) => return Some(true),
+                Some(false) => (),
+                None => complete = false,
+            }
+        }
+        match graph.parents.get(&component) {
+            Some(parents) => stack.extend(parents.iter().cloned()),
+            None if root.is_none() => complete = false,
+            None => (),
+        }
+    }
+    if complete { Some(false) } else { None }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_injections() {
+        let (program, _) = ts::Program::parse(r#"export default {
+            inject: { api: 'api', store: { from: 'vuex', default: null }, theme: { default: 'light' } }
+        }"#);
+        let options = crate::app::component_options(&program).unwrap();
+        let keys = injections(options).into_iter().map(|injection| (injection.name, injection.key)).collect::<Vec<_>>();
+        assert_eq!(keys, vec![
+            ("api".into(), "api".into()),
+            ("store".into(), "vuex".into()),
+            ("theme".into(), "theme".into()),
+        ]);
+
+        let (program, _) = ts::Program::parse("export default { inject: ['api', foo] }");
+        let options = crate::app::component_options(&program).unwrap();
+        let names = injections(options).into_iter().map(|injection| injection.name).collect::<Vec<_>>();
+        assert_eq!(names, vec![SmolStr::from("api")]);
+    }
+
+    #[cfg(feature = "runtime")]
+    #[test]
+    fn test_providers() {
+        use super::super::tests::check_files;
+
+        let child = r#"<template>
+  <p>{{ api }} {{ store }} {{ missing }}</p>
+</template>
+<script>
+export default { inject: ['api', 'store', 'missing'] };
+</script>"#;
+        let app = r#"<template>
+  <child-component></child-component>
+</template>
+<script>
+import ChildComponent from './Child.vue';
+export default {
+  components: { ChildComponent },
+  provide() { return { api: {} }; }
+};
+</script>"#;
+        let main = "import Vue from 'vue';\nimport App from './App.vue';\nnew Vue({ provide: { store: {} }, render: h => h(App) });";
+        assert_eq!(check_files(&[("src/Child.vue", child), ("src/App.vue", app), ("src/main.js", main)]), vec![
+            "warn(vue): [src/Child.vue:5:43] no ancestor component provides `missing`",
+        ]);
+
+        // The ancestors of a component which isn't rendered by a root instance aren't known
+        assert_eq!(check_files(&[("src/Child.vue", child), ("src/App.vue", app)]), Vec::<String>::new());
+    }
+}
@@ -0,0 +1,344 @@
+//! Checks the members (e.g. props, data and methods) defined by a component's options.
+use super::Diagnostic;
+use super::inject::injections;
+use crate::VueDatabase;
+use crate::app::{get_object_property, rendered_components, returned_expression, string_value, to_kebab_case};
+use code_analysis::FileId;
+use code_grammar::{AstNode, SmolStr, SyntaxNodeExt, TextUnit};
+use typescript_analysis::ty::infer_property_name;
+use typescript_grammar::ast as ts;
+use typescript_grammar::syntax_kind::THIS_EXPRESSION;
+use vue_grammar::ast as vue;
+use vue_grammar::syntax_kind::{ATTRIBUTE, ATTRIBUTE_BINDING};
+use rustc_hash::FxHashSet;
+
+/// A property which a component's options add to its `vm`.
+pub(super) struct Member {
+    pub name: SmolStr,
+    /// The option which defines the member (e.g. `props` or `methods`).
+    pub option: &'static str,
+    /// The offset of the member's definition within the script.
+    pub pos: TextUnit,
+}
+
+/// Finds the members defined by `props`, `data`, `computed`, `methods`, `inject` and `setup`.
+pub(super) fn members(vue_options: &ts::ObjectExpression) -> Vec<Member> {
+    let mut members = Vec::new();
+    if let Some(props) = get_object_property(vue_options, "props") {
+        match props.kind() {
+            ts::ExpressionKind::ArrayExpression(arr) => {
+                for el in arr.elements() {
+                    if let Some(name) = string_value(el) {
+                        members.push(Member { name, option: "props", pos: el.syntax.range().start() });
+                    }
+                }
+            }
+            ts::ExpressionKind::ObjectExpression(obj) => object_members(&mut members, obj, "props"),
+            _ => (),
+        }
+    }
+    if let Some(data) = get_object_property(vue_options, "data") {
+        let object = ts::ObjectExpression::downcast(data)
+            .or_else(|| returned_expression(data).and_then(ts::ObjectExpression::downcast));
+        if let Some(object) = object {
+            object_members(&mut members, object, "data");
+        }
+    }
+    for &option in &["computed", "methods"] {
+        if let Some(object) = get_object_property(vue_options, option).and_then(ts::ObjectExpression::downcast) {
+            object_members(&mut members, object, option);
+        }
+    }
+    for injection in injections(vue_options) {
+        members.push(Member { name: injection.name, option: "inject", pos: injection.pos });
+    }
+    let setup = get_object_property(vue_options, "setup")
+        .and_then(returned_expression)
+        .and_then(ts::ObjectExpression::downcast);
+    if let Some(object) = setup {
+        object_members(&mut members, object, "setup");
+    }
+    members
+}
+
+fn object_members(members: &mut Vec<Member>, object: &ts::ObjectExpression, option: &'static str) {
+    for prop in object.properties() {
+        if let Some(name) = infer_property_name(prop) {
+            members.push(Member { name, option, pos: prop.syntax.range().start() });
+        }
+    }
+}
+
+pub(super) fn check_members(vue_options: &ts::ObjectExpression, base: TextUnit, results: &mut Vec<Diagnostic>) {
+    let members = members(vue_options);
+    for (idx, member) in members.iter().enumerate() {
+        // Report collisions at both definitions
+        let mut previous = members[..idx].iter().filter(|other| other.name == member.name);
+        if let Some(first) = previous.next() {
+            let message = if first.option == member.option {
+                format!("`{}` is defined more than once in `{}`", member.name, member.option)
+            } else {
+                format!("`{}` is defined in both `{}` and `{}`", member.name, first.option, member.option)
+            };
+            if previous.next().is_none() {
+                results.push(Diagnostic::error("vue", base + first.pos, message.clone()));
+            }
+            results.push(Diagnostic::error("vue", base + member.pos, message));
+        }
+
+        // N.B. vue doesn't proxy data properties which could conflict with its own internal properties
+        if member.option == "data" && (member.name.starts_with('_') || member.name.starts_with('$')) {
+            let message = format!(
+                "data property `{}` is not available on the component because it starts with `{}`",
+                member.name,
+                &member.name[..1],
+            );
+            results.push(Diagnostic::warn("vue", base + member.pos, message));
+        }
+    }
+}
+
+/// Warns about members which aren't referenced by the component's template, its script or the
+/// bindings of any parent component which renders it.
+///
+/// The check is skipped whenever a member could be referenced in a way which can't be followed
+/// (e.g. from a mixin or with `this[key]`), or when the component's parents aren't known.
+pub(super) fn check_unused(
+    db: &impl VueDatabase,
+    file_id: FileId,
+    vue_options: &ts::ObjectExpression,
+    template_references: &FxHashSet<SmolStr>,
+    base: TextUnit,
+    results: &mut Vec<Diagnostic>,
+) {
+    for &option in &["extends", "mixins", "template"] {
+        if get_object_property(vue_options, option).is_some() {
+            return;
+        }
+    }
+    let mut references = template_references.clone();
+    if !script_references(vue_options, &mut references) || !parent_references(db, file_id, &mut references) {
+        return;
+    }
+    for member in members(vue_options) {
+        let whole_option = match member.option {
+            "data" => "$data",
+            "props" => "$props",
+            _ => "",
+        };
+        if references.contains(&member.name) ||
+            references.contains(to_kebab_case(&member.name).as_str()) ||
+            references.contains(whole_option)
+        {
+            continue;
+        }
+        let message = format!("`{}` in `{}` is never used", member.name, member.option);
+        results.push(Diagnostic::warn("pedantic", base + member.pos, message));
+    }
+}
+
+/// Collects the members referenced by the options themselves (e.g. `this.name` or `watch: { name: "update" }`).
+///
+/// Returns false if `this` is used in a way which could reference any member.
+fn script_references(vue_options: &ts::ObjectExpression, references: &mut FxHashSet<SmolStr>) -> bool {
+    for node in vue_options.syntax.descendants() {
+        if node.kind() != THIS_EXPRESSION {
+            continue;
+        }
+        let member = match node.parent().and_then(ts::MemberExpression::cast) {
+            Some(member) if member.object().map(|obj| obj.syntax.range()) == Some(node.range()) => member,
+            _ => return false, // N.B. e.g. `const vm = this` or `helper(this)`
+        };
+        let property = match member.property() {
+            Some(property) => property,
+            None => continue,
+        };
+        let name = match property.kind() {
+            ts::ExpressionKind::Identifier(ident) if !member.computed() => ident.name().into(),
+            _ => match string_value(property) {
+                Some(name) if member.computed() => name,
+                _ => return false,
+            },
+        };
+        references.insert(name);
+    }
+
+    // Watchers reference the properties they watch and their handlers by name
+    if let Some(watch) = get_object_property(vue_options, "watch").and_then(ts::ObjectExpression::downcast) {
+        for prop in watch.properties() {
+            if let Some(key) = infer_property_name(prop) {
+                references.insert(key.split('.').next().unwrap_or_default().into());
+            }
+        }
+        for node in watch.syntax.descendants() {
+            if let Some(name) = ts::Expression::cast(node).and_then(string_value) {
+                references.insert(name);
+            }
+        }
+    }
+    true
+}
+
+/// Collects the members bound by the parents which render the component (e.g. `<child :name="..." />`).
+///
+/// Returns false if a parent binds an object of props or takes a `ref` to the component.
+fn parent_references(db: &impl VueDatabase, file_id: FileId, references: &mut FxHashSet<SmolStr>) -> bool {
+    let graph = db.component_graph(db.file_source_root(file_id));
+    let parents = match graph.parents.get(&file_id) {
+        Some(parents) => parents,
+        // N.B. a component with no known parents might be rendered from somewhere we can't see
+        None => return graph.roots.contains_key(&file_id),
+    };
+    for &parent in parents {
+        let component = db.vue_ast(db.file_source(parent));
+        let template = match component.template() {
+            Some(template) => template,
+            None => continue,
+        };
+        for (element, child) in rendered_components(db, parent, &template.syntax) {
+            if child != file_id {
+                continue;
+            }
+            for attr in element.children() {
+                let name = match (attr.kind(), vue::Directive::cast(attr)) {
+                    // N.B. `v-bind="props"` can bind any prop
+                    (ATTRIBUTE, Some(directive)) if directive.name() == "bind" => return false,
+                    (ATTRIBUTE, _) => attr.first_token().map(|token| token.text().as_str()),
+                    (ATTRIBUTE_BINDING, Some(directive)) => match directive.argument() {
+                        // N.B. a dynamic argument (e.g. `:[name]`) could bind any prop
+                        Some(key) if key.is_dynamic() => return false,
+                        Some(key) => key.name(),
+                        None => None,
+                    },
+                    _ => None,
+                };
+                let name = match name {
+                    Some(name) => name,
+                    None => continue,
+                };
+                // N.B. `$refs` can access any member
+                if name == "ref" {
+                    return false;
+                }
+                references.insert(to_kebab_case(name).into());
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::component_options;
+    use code_analysis::LineIndex;
+
+    /// Checks the members of a component's options, returning the line of each diagnostic with its message.
+    fn check(script: &str) -> Vec<String> {
+        let (program, _) = ts::Program::parse(script);
+        let mut results = Vec::new();
+        check_members(component_options(&program).unwrap(), TextUnit::default(), &mut results);
+        let line_index = LineIndex::new(script);
+        results.into_iter().map(|diag| format!("{}: {}", line_index.line_col(diag.pos.unwrap()).line, diag.message)).collect()
+    }
+
+    #[test]
+    fn test_collisions() {
+        let script = r#"export default {
+            props: ['value', 'title'],
+            inject: ['api'],
+            data() { return { value: 1, count: 0 }; },
+            computed: { count() { return 0; } },
+            methods: { api() {}, title() {} },
+            setup() { return { total: 0 }; }
+        }"#;
+        assert_eq!(check(script), vec![
+            "1: `value` is defined in both `props` and `data`",
+            "3: `value` is defined in both `props` and `data`",
+            "3: `count` is defined in both `data` and `computed`",
+            "4: `count` is defined in both `data` and `computed`",
+            "1: `title` is defined in both `props` and `methods`",
+            "5: `title` is defined in both `props` and `methods`",
+            "5: `api` is defined in both `methods` and `inject`",
+            "2: `api` is defined in both `methods` and `inject`",
+        ]);
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let script = r#"export default {
+            props: { value: String },
+            methods: { save() {}, load() {}, save() {} },
+            setup() { return { value: 0 }; }
+        }"#;
+        assert_eq!(check(script), vec![
+            "2: `save` is defined more than once in `methods`",
+            "2: `save` is defined more than once in `methods`",
+            "1: `value` is defined in both `props` and `setup`",
+            "3: `value` is defined in both `props` and `setup`",
+        ]);
+    }
+
+    #[test]
+    fn test_reserved_data() {
+        let script = "export default {\n  data() { return { _private: 1, $state: 2, visible: true }; }\n}";
+        assert_eq!(check(script), vec![
+            "1: data property `_private` is not available on the component because it starts with `_`",
+            "1: data property `$state` is not available on the component because it starts with `$`",
+        ]);
+        assert_eq!(check("export default { computed: { _private() { return 1; } } }"), Vec::<String>::new());
+    }
+
+    #[cfg(feature = "runtime")]
+    #[test]
+    fn test_unused() {
+        use super::super::tests::check_files;
+
+        let child = r#"<template>
+  <p v-show="visible" v-focus="target">{{ label }}</p>
+</template>
+<script>
+export default {
+  directives: { focus: {} },
+  props: ['label', 'size', 'unused'],
+  data() { return { visible: true, target: null, stale: 0 }; },
+  methods: { reset() { this.visible = false; }, helper() {} },
+  mounted() { this.reset(); }
+};
+</script>"#;
+        let app = r#"<template>
+  <child-component size="large"></child-component>
+</template>
+<script>
+import ChildComponent from './Child.vue';
+export default { components: { ChildComponent } };
+</script>"#;
+        let main = "import Vue from 'vue';\nimport App from './App.vue';\nnew Vue({ render: h => h(App) });";
+        assert_eq!(check_files(&[("src/Child.vue", child), ("src/App.vue", app), ("src/main.js", main)]), vec![
+            "warn(pedantic): [src/Child.vue:7:28] `unused` in `props` is never used",
+            "warn(pedantic): [src/Child.vue:8:50] `stale` in `data` is never used",
+            "warn(pedantic): [src/Child.vue:9:49] `helper` in `methods` is never used",
+        ]);
+
+        // The bindings of a component which isn't rendered by a known parent can't be checked
+        assert_eq!(check_files(&[("src/Child.vue", child)]), Vec::<String>::new());
+
+        // Members referenced in statements and spreads are found, but a template literal's aren't
+        let child = r#"<template>
+  <button @click="while (count < 3) count++" @focus="go(...items)" :title="label">Go</button>
+</template>
+<script>
+export default {
+  props: ['label'],
+  data() { return { count: 0, items: [], stale: 0 }; },
+  methods: { go(...args) {} }
+};
+</script>"#;
+        let app = app.replace(" size=\"large\"", " label=\"Go\"");
+        assert_eq!(check_files(&[("src/Child.vue", child), ("src/App.vue", &app), ("src/main.js", main)]), vec![
+            "warn(pedantic): [src/Child.vue:7:42] `stale` in `data` is never used",
+        ]);
+        let child = child.replace("count++", "count++; go(`a${stale}`)");
+        assert_eq!(check_files(&[("src/Child.vue", &child), ("src/App.vue", &app), ("src/main.js", main)]), Vec::<String>::new());
+    }
+}
@@ -0,0 +1,339 @@
+//! Checks the targets and modifiers of `v-model` directives.
+use super::{collect_pattern_decls_and_captures, directive_code, Captures, Diagnostic};
+use super::members::{members, Member};
+use crate::VueDatabase;
+use crate::ast::{attribute_value, TemplateCode, TemplateExpressionKind};
+use crate::app::{component_options, get_object_property, rendered_components, script_source, string_value};
+use code_analysis::{original_range, FileId, SourceId};
+use code_grammar::{AstNode, SmolStr, SyntaxElement, SyntaxNode, SyntaxNodeExt, SyntaxToken};
+use typescript_grammar::ast as ts;
+use vue_grammar::ast as vue;
+use vue_grammar::syntax_kind::*;
+
+/// Native elements which support `v-model`.
+const ELEMENTS: &[&str] = &["input", "select", "textarea"];
+
+/// Modifiers which `v-model` supports on native elements.
+const ELEMENT_MODIFIERS: &[&str] = &["lazy", "number", "trim"];
+
+/// Modifiers which `v-model` supports on components.
+const COMPONENT_MODIFIERS: &[&str] = &["number", "trim"];
+
+pub(super) fn check_models(
+    db: &impl VueDatabase,
+    file_id: FileId,
+    template: &vue::Template,
+    vue_options: &ts::ObjectExpression,
+    results: &mut Vec<Diagnostic>,
+) {
+    let rendered = rendered_components(db, file_id, &template.syntax);
+    let members = members(vue_options);
+    for directive in template.syntax.descendants().filter_map(vue::Directive::cast) {
+        if directive.name() != "model" {
+            continue;
+        }
+        let element = match directive.syntax.parent() {
+            Some(element) => element,
+            None => continue,
+        };
+        let tag = match tag_name(element) {
+            Some(tag) => tag,
+            None => continue,
+        };
+        let tag_pos = tag.range().start();
+        let tag = tag.text().as_str();
+        let child = rendered.iter().find(|(node, _)| node.range() == element.range()).map(|&(_, child)| child);
+
+        // Check the element (or component) supports `v-model`
+        let mut modifiers = match child {
+            Some(child) => match component_model(db, child) {
+                Some(model) => {
+                    if let Some(false) = model.has_prop {
+                        let message = format!("component `{}` has no `{}` prop to bind with `v-model`", tag, model.prop);
+                        results.push(Diagnostic::error("vue", directive.syntax.range().start(), message));
+                    }
+                    if model.custom_modifiers { None } else { Some(COMPONENT_MODIFIERS) }
+                }
+                None => None,
+            },
+            None if is_component_tag(tag) => None,
+            None if !ELEMENTS.contains(&tag) => {
+                let message = format!("`v-model` is not supported on `<{}>` elements", tag);
+                results.push(Diagnostic::error("vue", tag_pos, message));
+                None
+            }
+            None if tag == "input" && attribute_text(element, "type") == Some("file") => {
+                results.push(Diagnostic::error("vue", tag_pos, "`v-model` is not supported on file inputs, because they are read-only"));
+                None
+            }
+            None => Some(ELEMENT_MODIFIERS),
+        };
+
+        // Check the modifiers are supported
+        for (modifier, range) in directive.modifiers() {
+            if modifiers.map(|known| !known.contains(&modifier)).unwrap_or(false) {
+                let message = format!("unknown `v-model` modifier `.{}`", modifier);
+                results.push(Diagnostic::error("vue", range.start(), message));
+                modifiers = None; // N.B. only report the first unknown modifier
+            }
+        }
+
+        check_target(db, file_id, vue_options, &members, element, directive, results);
+    }
+}
+
+/// Checks the value of a `v-model` directive can be assigned to.
+fn check_target(
+    db: &impl VueDatabase,
+    file_id: FileId,
+    vue_options: &ts::ObjectExpression,
+    members: &[Member],
+    element: &SyntaxNode,
+    directive: &vue::Directive,
+    results: &mut Vec<Diagnostic>,
+) {
+    let src_id = db.file_source(file_id);
+    let (expr, expr_src_id) = match directive_code(db, src_id, directive) {
+        Some((TemplateCode::Expression(expr), expr_src_id)) => (expr, expr_src_id),
+        _ => return,
+    };
+    let pos = match original_range(db, expr_src_id, expr.syntax.range()) {
+        Some(original) => original.range.start(),
+        None => return,
+    };
+    let ident = match expr.kind() {
+        ts::ExpressionKind::Identifier(ident) => ident.name(),
+        ts::ExpressionKind::MemberExpression(_) => return,
+        _ => {
+            let message = "`v-model` must be bound to a property or a member expression (e.g. `name` or `form.name`)";
+            results.push(Diagnostic::error("vue", pos, message));
+            return;
+        }
+    };
+    match template_scope(db, src_id, element, ident) {
+        Scope::Alias => {
+            let message = format!("`v-model` cannot be bound to the `v-for` alias `{}`; bind to a property of it instead", ident);
+            results.push(Diagnostic::error("vue", pos, message));
+            return;
+        }
+        Scope::Slot => return,
+        Scope::Component => (),
+    }
+    let member = match members.iter().find(|member| member.name == ident) {
+        Some(member) => member,
+        None => return,
+    };
+    let message = match member.option {
+        "props" => format!("`v-model` cannot be bound to the prop `{}`, because props are read-only", ident),
+        "methods" => format!("`v-model` cannot be bound to the method `{}`", ident),
+        "computed" if !has_setter(vue_options, ident) => {
+            format!("`v-model` cannot be bound to the computed property `{}`, because it has no setter", ident)
+        }
+        _ => return,
+    };
+    results.push(Diagnostic::error("vue", pos, message));
+}
+
+enum Scope {
+    /// Declared by `v-for` on the element or one of its ancestors.
+    Alias,
+    /// Declared by a slot's scope (e.g. `v-slot` or `slot-scope`) on the element or one of its ancestors.
+    Slot,
+    /// Not declared in the template.
+    Component,
+}
+
+/// Finds where a name is declared, using the patterns parsed from the template (e.g. `item` in
+/// `v-for="(item, index) in items"`) for the element and each of its ancestors.
+fn template_scope(db: &impl VueDatabase, src_id: SourceId, element: &SyntaxNode, ident: &str) -> Scope {
+    let patterns: Vec<_> = db.template_expressions(src_id).iter()
+        .map(|&expr_id| (db.lookup_intern_template_expression(expr_id), expr_id))
+        .filter(|(expr, _)| expr.kind == TemplateExpressionKind::Pattern)
+        .collect();
+    let mut node = Some(element);
+    while let Some(element) = node {
+        node = element.parent();
+        if element.kind() != ELEMENT {
+            continue;
+        }
+        for (expr, expr_id) in patterns.iter().filter(|(expr, _)| expr.scope == element.range()) {
+            let pattern = match db.template_code(*expr_id) {
+                TemplateCode::Pattern(pattern) => pattern,
+                _ => continue,
+            };
+            let mut decls = Vec::new();
+            collect_pattern_decls_and_captures(&pattern, &mut decls, &mut Captures::default(), true);
+            if !decls.contains(&ident) {
+                continue;
+            }
+            let is_alias = element.children()
+                .filter_map(vue::Directive::cast)
+                .filter(|directive| directive.name() == "for")
+                .any(|directive| directive.value_range().map(|range| expr.range.is_subrange(&range)).unwrap_or(false));
+            return if is_alias { Scope::Alias } else { Scope::Slot };
+        }
+    }
+    Scope::Component
+}
+
+fn has_setter(vue_options: &ts::ObjectExpression, ident: &str) -> bool {
+    get_object_property(vue_options, "computed")
+        .and_then(ts::ObjectExpression::downcast)
+        .and_then(|computed| get_object_property(computed, ident))
+        .and_then(ts::ObjectExpression::downcast)
+        .map(|accessors| get_object_property(accessors, "set").is_some())
+        .unwrap_or(false)
+}
+
+/// How a component binds `v-model` (e.g. `model: { prop: "checked", event: "change" }`).
+struct Model {
+    prop: SmolStr,
+    /// Whether the component declares the prop, or `None` if its props can't be determined.
+    has_prop: Option<bool>,
+    /// Whether the component accepts any modifiers with a `modelModifiers` prop.
+    custom_modifiers: bool,
+}
+
+fn component_model(db: &impl VueDatabase, file_id: FileId) -> Option<Model> {
+    let program = db.typescript_ast(script_source(db, file_id)?);
+    let options = component_options(&program)?;
+    let prop = get_object_property(options, "model")
+        .and_then(ts::ObjectExpression::downcast)
+        .and_then(|model| get_object_property(model, "prop"))
+        .and_then(string_value)
+        .unwrap_or_else(|| "value".into());
+    let members = members(options);
+    let props = || members.iter().filter(|member| member.option == "props");
+    let inherits_props = ["extends", "mixins"].iter().any(|&option| get_object_property(options, option).is_some());
+    let has_prop = if inherits_props || get_object_property(options, "props").is_none() {
+        None
+    } else {
+        Some(props().any(|member| member.name == prop))
+    };
+    let custom_modifiers = props().any(|member| member.name == "modelModifiers");
+    Some(Model { prop, has_prop, custom_modifiers })
+}
+
+pub(super) fn tag_name(element: &SyntaxNode) -> Option<SyntaxToken> {
+    element.children_with_tokens().find_map(|el| match el {
+        SyntaxElement::Token(token) if token.kind() == TAG_NAME => Some(token),
+        _ => None,
+    })
+}
+
+/// Returns true if a tag may be a component, even if it isn't registered (e.g. `my-button` or `MyButton`).
+pub(super) fn is_component_tag(tag: &str) -> bool {
+    tag == "component" || tag.contains('-') || tag.starts_with(|c: char| c.is_uppercase())
+}
+
+/// Finds the unquoted text of an element's static attribute (e.g. `type="file"`).
+pub(super) fn attribute_text<'a>(element: &'a SyntaxNode, name: &str) -> Option<&'a str> {
+    let attr = element.children()
+        .filter(|node| node.kind() == ATTRIBUTE)
+        .find(|node| node.first_token().map(|token| token.text() == name).unwrap_or(false))?;
+    let value = attribute_value(attr)?;
+    let text = value.text().as_str();
+    if value.kind() == QUOTED {
+        Some(&text[1 .. text.len() - 1])
+    } else {
+        Some(text)
+    }
+}
+
+#[cfg(all(test, feature = "runtime"))]
+mod tests {
+    /// Checks the first of the files, ignoring accessibility warnings about the unlabelled inputs.
+    fn check_files(files: &[(&str, &str)]) -> Vec<String> {
+        let mut results = super::super::tests::check_files(files);
+        results.retain(|result| !result.starts_with("warn(a11y)"));
+        results
+    }
+
+    fn check_component(text: &str) -> Vec<String> {
+        check_files(&[("src/App.vue", text)])
+    }
+
+    #[test]
+    fn test_targets() {
+        let component = r#"<template>
+  <form>
+    <input v-model="name"><input v-model="form.email"><input v-model="label">
+    <input v-model="save"><input v-model="upper"><input v-model="full">
+    <input v-model="save()">
+    <p v-for="(item, index) in items"><input v-model="item"><input v-model="item.text"></p>
+  </form>
+</template>
+<script>
+export default {
+  props: ['label'],
+  data() { return { name: '', form: {}, items: [] }; },
+  computed: {
+    upper() { return this.name.toUpperCase(); },
+    full: { get() { return this.name; }, set(value) { this.name = value; } }
+  },
+  methods: { save() {} }
+};
+</script>"#;
+        assert_eq!(check_component(component), vec![
+            "error(vue): [src/App.vue:3:71] `v-model` cannot be bound to the prop `label`, because props are read-only",
+            "error(vue): [src/App.vue:4:21] `v-model` cannot be bound to the method `save`",
+            "error(vue): [src/App.vue:4:43] `v-model` cannot be bound to the computed property `upper`, because it has no setter",
+            "error(vue): [src/App.vue:5:21] `v-model` must be bound to a property or a member expression (e.g. `name` or `form.name`)",
+            "error(vue): [src/App.vue:6:55] `v-model` cannot be bound to the `v-for` alias `item`; bind to a property of it instead",
+        ]);
+    }
+
+    #[test]
+    fn test_elements_and_modifiers() {
+        let component = r#"<template>
+  <div>
+    <input v-model.trim.lazy="name"><input v-model.capitalize="name"><textarea v-model.number="name"></textarea>
+    <div v-model="name"></div><input type="file" v-model="name">
+    <child-component v-model.trim="name"></child-component>
+    <child-component v-model.lazy="name"></child-component>
+  </div>
+</template>
+<script>
+import ChildComponent from './Child.vue';
+export default {
+  components: { ChildComponent },
+  data() { return { name: '' }; }
+};
+</script>"#;
+        let child = "<script>\nexport default { model: { prop: 'checked' }, props: ['checked'] };\n</script>";
+        assert_eq!(check_files(&[("src/App.vue", component), ("src/Child.vue", child)]), vec![
+            "error(vue): [src/App.vue:3:52] unknown `v-model` modifier `.capitalize`",
+            "error(vue): [src/App.vue:4:6] `v-model` is not supported on `<div>` elements",
+            "error(vue): [src/App.vue:4:32] `v-model` is not supported on file inputs, because they are read-only",
+            "error(vue): [src/App.vue:6:30] unknown `v-model` modifier `.lazy`",
+        ]);
+
+        let child = child.replace("props: ['checked']", "props: ['value']");
+        let errors = check_files(&[("src/App.vue", component), ("src/Child.vue", &child)]);
+        assert!(errors.contains(&"error(vue): [src/App.vue:5:22] component `child-component` has no `checked` prop to bind with `v-model`".to_string()));
+    }
+
+    #[test]
+    fn test_template_scope() {
+        let component = r#"<template>
+  <div>
+    <p v-for="({ id, name }, index) of rows"><input v-model="name"><input v-model="label"></p>
+    <my-list><template v-slot:item="{ label }"><input v-model="label"></template></my-list>
+    <my-list><p slot-scope="{ labelText }"><input v-model="label"></p></my-list>
+  </div>
+</template>
+<script>
+import Vue from 'vue';
+Vue.component('my-list', {});
+export default {
+  props: ['label'],
+  data() { return { rows: [] }; }
+};
+</script>"#;
+        assert_eq!(check_component(component), vec![
+            "error(vue): [src/App.vue:3:62] `v-model` cannot be bound to the `v-for` alias `name`; bind to a property of it instead",
+            "error(vue): [src/App.vue:3:84] `v-model` cannot be bound to the prop `label`, because props are read-only",
+            "error(vue): [src/App.vue:5:60] `v-model` cannot be bound to the prop `label`, because props are read-only",
+        ]);
+    }
+}
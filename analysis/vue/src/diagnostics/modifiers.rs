@@ -0,0 +1,173 @@
+//! Checks the modifiers of `v-on` listeners and `v-bind` bindings (e.g. `@click.prevent` or `:title.sync`).
+use super::Diagnostic;
+use super::model::{is_component_tag, tag_name};
+use super::options::suggest;
+use crate::{Config, VueDatabase};
+use crate::app::rendered_components;
+use code_analysis::FileId;
+use code_grammar::{AstNode, SyntaxNodeExt, TextUnit};
+use vue_grammar::ast as vue;
+use vue_grammar::syntax_kind::*;
+
+/// Modifiers which apply to any event.
+const EVENT_MODIFIERS: &[&str] = &["capture", "native", "once", "passive", "prevent", "self", "stop"];
+
+/// Modifiers which only trigger a listener if the modifier keys are pressed (or with `exact`, only those keys).
+const SYSTEM_MODIFIERS: &[&str] = &["alt", "ctrl", "exact", "meta", "shift"];
+
+/// Modifiers which only trigger a mouse event's listener for a particular button.
+const MOUSE_MODIFIERS: &[&str] = &["left", "middle", "right"];
+
+/// Modifiers which only trigger a keyboard event's listener for a particular key.
+///
+/// Besides vue's own aliases, this includes the kebab-cased `KeyboardEvent.key` names in common use.
+const KEY_MODIFIERS: &[&str] = &[
+    "arrow-down",
+    "arrow-left",
+    "arrow-right",
+    "arrow-up",
+    "backspace",
+    "caps-lock",
+    "delete",
+    "down",
+    "end",
+    "enter",
+    "esc",
+    "escape",
+    "home",
+    "insert",
+    "left",
+    "page-down",
+    "page-up",
+    "right",
+    "space",
+    "tab",
+    "up",
+];
+
+const KEY_EVENTS: &[&str] = &["keydown", "keypress", "keyup"];
+
+const BINDING_MODIFIERS: &[&str] = &["camel", "prop", "sync"];
+
+pub(super) fn check_modifiers(
+    db: &impl VueDatabase,
+    file_id: FileId,
+    template: &vue::Template,
+    config: &Config,
+    results: &mut Vec<Diagnostic>,
+) {
+    let rendered = rendered_components(db, file_id, &template.syntax);
+    for directive in template.syntax.descendants().filter_map(vue::Directive::cast) {
+        match directive.syntax.kind() {
+            ATTRIBUTE_LISTENER => (),
+            ATTRIBUTE_BINDING => {
+                for (modifier, range) in directive.modifiers() {
+                    if !BINDING_MODIFIERS.contains(&modifier) {
+                        unknown_modifier(results, "v-bind", modifier, range.start(), BINDING_MODIFIERS.iter().cloned());
+                    }
+                }
+                continue;
+            }
+            ATTRIBUTE => {
+                // N.B. `.sync` is also allowed on `v-bind="props"`
+                if directive.name() != "bind" {
+                    if let Some((_, range)) = directive.modifiers().into_iter().find(|&(modifier, _)| modifier == "sync") {
+                        results.push(Diagnostic::error("vue", range.start(), "`.sync` can only be used with `v-bind`"));
+                    }
+                }
+                continue;
+            }
+            _ => continue,
+        }
+
+        let is_native = directive.syntax.parent()
+            .filter(|element| rendered.iter().all(|(node, _)| node.range() != element.range()))
+            .and_then(tag_name)
+            .map(|tag| !is_component_tag(tag.text()))
+            .unwrap_or(false);
+        let event = directive.argument().and_then(vue::AttributeKey::name);
+        let is_key_event = event.map(|event| KEY_EVENTS.contains(&event)).unwrap_or(false);
+        let known = || {
+            let key_modifiers = if is_key_event { KEY_MODIFIERS } else { MOUSE_MODIFIERS };
+            EVENT_MODIFIERS.iter().cloned()
+                .chain(SYSTEM_MODIFIERS.iter().cloned())
+                .chain(key_modifiers.iter().cloned())
+                .chain(config.global.key_codes.iter().map(String::as_str).filter(move |_| is_key_event))
+        };
+        for (modifier, range) in directive.modifiers() {
+            let pos = range.start();
+            if modifier == "sync" {
+                results.push(Diagnostic::error("vue", pos, "`.sync` can only be used with `v-bind`"));
+            } else if modifier == "native" && is_native {
+                results.push(Diagnostic::error("vue", pos, "`.native` can only be used on components"));
+            } else if !known().any(|known| known == modifier) && !(is_key_event && is_key_name(modifier)) {
+                unknown_modifier(results, "v-on", modifier, pos, known());
+            }
+        }
+    }
+}
+
+/// Returns true for key modifiers which name a single character, a function key or a key code (e.g. `a`, `f5` or `13`).
+fn is_key_name(modifier: &str) -> bool {
+    let mut chars = modifier.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => c.is_ascii_alphanumeric(),
+        (Some('f'), Some(_)) => modifier[1..].parse::<u8>().map(|n| n >= 1 && n <= 24).unwrap_or(false),
+        _ => modifier.chars().all(|c| c.is_ascii_digit()),
+    }
+}
+
+fn unknown_modifier<'a>(
+    results: &mut Vec<Diagnostic>,
+    directive: &str,
+    modifier: &str,
+    pos: TextUnit,
+    known: impl Iterator<Item = &'a str>,
+) {
+    let message = match suggest(modifier, known) {
+        Some(known) => format!("unknown `{}` modifier `.{}`, did you mean `.{}`?", directive, modifier, known),
+        None => format!("unknown `{}` modifier `.{}`", directive, modifier),
+    };
+    results.push(Diagnostic::error("vue", pos, message));
+}
+
+#[cfg(all(test, feature = "runtime"))]
+mod tests {
+    use super::super::tests::check_component;
+
+    #[test]
+    fn test_listeners() {
+        let component = r#"<template>
+  <form v-on:submit.prevent="save" @click.stop.self="save" v-on:click.prevnt="save" @click.sync="save" role="form" @keydown.enter="save">
+    <input v-on:keyup.enter.ctrl="save" @keydown.a.f5.13="save" @keyup.entr="save" @click.native="save" aria-label="name">
+    <my-button @click.native="save" v-on:click.native.once="save"></my-button>
+  </form>
+</template>
+<script>
+import Vue from 'vue';
+Vue.component('my-button', {});
+export default { methods: { save() {} } };
+</script>"#;
+        assert_eq!(check_component(component), vec![
+            "error(vue): [src/App.vue:2:71] unknown `v-on` modifier `.prevnt`, did you mean `.prevent`?",
+            "error(vue): [src/App.vue:2:92] `.sync` can only be used with `v-bind`",
+            "error(vue): [src/App.vue:3:72] unknown `v-on` modifier `.entr`, did you mean `.enter`?",
+            "error(vue): [src/App.vue:3:91] `.native` can only be used on components",
+        ]);
+    }
+
+    #[test]
+    fn test_bindings() {
+        let component = r#"<template>
+  <p :title.camel="title" v-bind:title.sync="title" v-bind:title.snyc="title" :title.once="title" v-focus.sync="title"></p>
+</template>
+<script>
+export default { directives: { focus: {} }, data() { return { title: '' }; } };
+</script>"#;
+        assert_eq!(check_component(component), vec![
+            "error(vue): [src/App.vue:2:66] unknown `v-bind` modifier `.snyc`, did you mean `.sync`?",
+            "error(vue): [src/App.vue:2:86] unknown `v-bind` modifier `.once`",
+            "error(vue): [src/App.vue:2:107] `.sync` can only be used with `v-bind`",
+        ]);
+    }
+}
@@ -0,0 +1,160 @@
+//! Checks the keys of a component's options object for unknown options (e.g. `mouted()`).
+use super::Diagnostic;
+use crate::Config;
+use code_grammar::TextUnit;
+use typescript_analysis::ty::infer_property_name;
+use typescript_grammar::ast as ts;
+
+pub(super) fn check_options(
+    vue_options: &ts::ObjectExpression,
+    config: &Config,
+    base: TextUnit,
+    results: &mut Vec<Diagnostic>,
+) {
+    let known = || OPTIONS.iter().cloned().chain(config.global.options.iter().map(String::as_str));
+    for prop in vue_options.properties() {
+        let key = match infer_property_name(prop) {
+            Some(key) => key,
+            None => continue,
+        };
+        if known().any(|option| key == option) {
+            continue;
+        }
+        let pos = base + prop.syntax.range().start();
+        let message = match suggest(&key, known()) {
+            Some(option) => format!("unknown component option `{}`, did you mean `{}`?", key, option),
+            None => format!("unknown component option `{}`", key),
+        };
+        results.push(Diagnostic::warn("vue", pos, message));
+    }
+}
+
+/// The number of edits at which an option is no longer considered a typo.
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// Finds the candidate closest to a misspelled name, if any is close enough to be a typo.
+pub(super) fn suggest<'a>(name: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    candidates
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// The Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + if ca == *cb { 0 } else { 1 };
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Options understood by vue itself (including lifecycle hooks), and plugins supported by the analyzer.
+const OPTIONS: &[&str] = &[
+    // Data
+    "data",
+    "props",
+    "propsData",
+    "emits",
+    "computed",
+    "methods",
+    "watch",
+    // DOM
+    "el",
+    "template",
+    "render",
+    "renderError",
+    // Lifecycle Hooks
+    "beforeCreate",
+    "created",
+    "beforeMount",
+    "mounted",
+    "beforeUpdate",
+    "updated",
+    "activated",
+    "deactivated",
+    "beforeDestroy",
+    "destroyed",
+    "errorCaptured",
+    "serverPrefetch",
+    // Assets
+    "directives",
+    "filters",
+    "components",
+    // Composition
+    "setup",
+    "parent",
+    "mixins",
+    "extends",
+    "provide",
+    "inject",
+    // Misc
+    "name",
+    "delimiters",
+    "functional",
+    "model",
+    "inheritAttrs",
+    "comments",
+    // Plugins
+    "apollo",
+    "store",
+    // Router Guards
+    "beforeRouteEnter",
+    "beforeRouteUpdate",
+    "beforeRouteLeave",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::component_options;
+
+    fn check(script: &str, config: &Config) -> Vec<String> {
+        let (program, _) = ts::Program::parse(script);
+        let mut results = Vec::new();
+        check_options(component_options(&program).unwrap(), config, TextUnit::default(), &mut results);
+        results.into_iter().map(|diag| diag.message).collect()
+    }
+
+    #[test]
+    fn test_check_options() {
+        let script = r#"export default {
+            setup() {},
+            emits: ['change'],
+            beforeRouteEnter(to, from, next) {},
+            beforeRouteUpdate(to, from, next) {},
+            beforeRouteLeave(to, from, next) {},
+            mouted() {},
+            metaInfo: {},
+            head: {}
+        }"#;
+        assert_eq!(check(script, &Config::default()), vec![
+            "unknown component option `mouted`, did you mean `mounted`?",
+            "unknown component option `metaInfo`",
+            "unknown component option `head`",
+        ]);
+
+        let mut config = Config::default();
+        config.global.options.push("metaInfo".into());
+        assert_eq!(check(script, &config), vec![
+            "unknown component option `mouted`, did you mean `mounted`?",
+            "unknown component option `head`",
+        ]);
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("mounted", "mounted"), 0);
+        assert_eq!(edit_distance("mouted", "mounted"), 1);
+        assert_eq!(edit_distance("computeds", "computed"), 1);
+        assert_eq!(edit_distance("", "data"), 4);
+    }
+}
@@ -0,0 +1,164 @@
+//! Checks templates and component scripts for patterns which are prone to XSS attacks (e.g. `v-html="comment"`).
+use super::Diagnostic;
+use super::a11y::{literal, Attributes, Value};
+use code_grammar::{AstNode, SyntaxNode, SyntaxNodeExt, TextUnit};
+use typescript_grammar::ast as ts;
+use typescript_grammar::syntax_kind::{BINARY_EXPRESSION, PLUS, TEMPLATE_LITERAL};
+use vue_grammar::ast as vue;
+use vue_grammar::syntax_kind::ELEMENT;
+
+/// Attributes which are navigated to or loaded as a URL.
+const URL_ATTRIBUTES: &[&str] = &["href", "src"];
+
+/// Properties which parse the assigned string as html.
+const HTML_PROPERTIES: &[&str] = &["innerHTML", "outerHTML"];
+
+/// Checks the elements of a template or html document.
+pub(super) fn check_template(root: &SyntaxNode, results: &mut Vec<Diagnostic>) {
+    for element in root.descendants().filter(|node| node.kind() == ELEMENT) {
+        for directive in element.children().filter_map(vue::Directive::cast) {
+            if directive.name() != "html" {
+                continue;
+            }
+            if let Some(value) = directive.value() {
+                if literal(value).is_none() {
+                    let message = "`v-html` renders its value as raw html, which can lead to XSS attacks; \
+                                   prefer interpolation or sanitize the html";
+                    results.push(Diagnostic::warn("security", directive.syntax.range().start(), message));
+                }
+            }
+        }
+
+        let attrs = Attributes::of(element);
+        if let Some(Value::Static("_blank")) = attrs.get("target") {
+            let safe = match attrs.get("rel") {
+                Some(Value::Static(rel)) => rel.split_whitespace().any(|rel| rel == "noopener" || rel == "noreferrer"),
+                Some(Value::Bound(_)) => true,
+                None => attrs.spread,
+            };
+            if !safe {
+                let message = "`target=\"_blank\"` without `rel=\"noopener\"` lets the opened page access `window.opener`";
+                results.push(Diagnostic::warn("security", attrs.pos("target"), message));
+            }
+        }
+        for attr in attrs.items.iter().filter(|attr| URL_ATTRIBUTES.contains(&attr.name)) {
+            match attr.value {
+                Value::Static(url) if is_javascript_url(url) => {
+                    let message = format!("`{}` uses a `javascript:` URL, which executes code when it's loaded", attr.name);
+                    results.push(Diagnostic::warn("security", attr.pos, message));
+                }
+                Value::Bound(expr) if is_concatenation(expr) => {
+                    let message = format!(
+                        "`{}` is built from a string, so it may be a `javascript:` URL; validate the URL's protocol",
+                        attr.name,
+                    );
+                    results.push(Diagnostic::warn("security", attr.pos, message));
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Checks a script for calls which evaluate strings as code or html.
+pub(super) fn check_script(program: &ts::Program, base: TextUnit, results: &mut Vec<Diagnostic>) {
+    for node in program.syntax.descendants() {
+        let pos = base + node.range().start();
+        if let Some(call) = ts::CallExpression::cast(node) {
+            match call.callee().and_then(ts::Identifier::downcast).map(|ident| ident.name()) {
+                Some("eval") => {
+                    let message = "`eval` executes a string as code, which can lead to XSS attacks";
+                    results.push(Diagnostic::warn("security", pos, message));
+                }
+                Some("Function") => {
+                    let message = "`Function` executes a string as code, which can lead to XSS attacks";
+                    results.push(Diagnostic::warn("security", pos, message));
+                }
+                _ => (),
+            }
+        } else if let Some(new) = ts::NewExpression::cast(node) {
+            if new.callee().and_then(ts::Identifier::downcast).map(|ident| ident.name()) == Some("Function") {
+                let message = "`new Function` executes a string as code, which can lead to XSS attacks";
+                results.push(Diagnostic::warn("security", pos, message));
+            }
+        } else if let Some(assign) = ts::AssignmentExpression::cast(node) {
+            let member = match assign.left().and_then(ts::MemberExpression::downcast) {
+                Some(member) if !member.computed() => member,
+                _ => continue,
+            };
+            let property = match member.property().and_then(ts::Identifier::downcast) {
+                Some(property) if HTML_PROPERTIES.contains(&property.name()) => property.name(),
+                _ => continue,
+            };
+            let message = format!("assigning to `{}` renders raw html, which can lead to XSS attacks", property);
+            results.push(Diagnostic::warn("security", pos, message));
+        }
+    }
+}
+
+fn is_javascript_url(url: &str) -> bool {
+    // N.B. browsers ignore whitespace and control characters in the protocol (e.g. `java\tscript:`)
+    let protocol: String = url
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .take("javascript:".len())
+        .collect();
+    protocol.eq_ignore_ascii_case("javascript:")
+}
+
+/// Whether a binding builds a string from other values (e.g. `'/users/' + id` or `` `${base}/users` ``).
+fn is_concatenation(expr: &str) -> bool {
+    let (expr, _) = ts::Expression::parse(expr.trim());
+    if !expr.errors().is_empty() {
+        return false;
+    }
+    match expr.syntax.kind() {
+        BINARY_EXPRESSION => expr.syntax.children_with_tokens().any(|child| child.kind() == PLUS),
+        _ => match expr.syntax.first_token() {
+            Some(token) if token.kind() == TEMPLATE_LITERAL => {
+                token.range() == expr.syntax.range() && token.text().contains("${")
+            }
+            _ => false,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_javascript_url() {
+        assert!(is_javascript_url("javascript:void(0)"));
+        assert!(is_javascript_url(" JavaScript:alert(1)"));
+        assert!(is_javascript_url("java\tscript:alert(1)"));
+        assert!(!is_javascript_url("/javascript:"));
+        assert!(!is_javascript_url("https://example.com"));
+    }
+}
+
+#[cfg(all(test, feature = "runtime"))]
+mod runtime_tests {
+    use super::super::tests::check_component;
+
+    #[test]
+    fn test_raw_html() {
+        let component = r#"<template>
+  <div>
+    <p v-html="'<b>static</b>'"></p>
+    <p v-html="'<b>' + name + '</b>'"></p>
+    <a :href="'/users/' + name">{{ name }}</a>
+  </div>
+</template>
+<script>
+export default {
+  data() { return { name: '' }; }
+}
+</script>
+"#;
+        assert_eq!(check_component(component), vec![
+            "warn(security): [src/App.vue:4:8] `v-html` renders its value as raw html, which can lead to XSS attacks; prefer interpolation or sanitize the html",
+            "warn(security): [src/App.vue:5:8] `href` is built from a string, so it may be a `javascript:` URL; validate the URL's protocol",
+        ]);
+    }
+}
@@ -0,0 +1,284 @@
+//! Inline comments which suppress diagnostics.
+//!
+//! ```text
+//! <!-- vue-analyzer-disable-next-line vue -->
+//! // vue-analyzer-disable-next-line
+//! /* vue-analyzer-disable style, pedantic */ ... /* vue-analyzer-enable */
+//! ```
+//!
+//! A suppression without any rule names suppresses every rule.
+use super::Diagnostic;
+use crate::VueDatabase;
+use code_analysis::{original_position, FileId, LineIndex};
+use code_grammar::{SyntaxElement, SyntaxNode, TextRange, TextUnit, WalkEvent};
+use code_grammar::syntax_kind::COMMENT;
+use std::sync::Arc;
+
+const DISABLE_NEXT_LINE: &str = "vue-analyzer-disable-next-line";
+const DISABLE: &str = "vue-analyzer-disable";
+const ENABLE: &str = "vue-analyzer-enable";
+
+pub(super) fn collect(db: &impl VueDatabase, file_id: FileId) -> Suppressions {
+    let path = db.file_relative_path(file_id);
+    let src_id = db.file_source(file_id);
+    let text = db.source_text(src_id);
+    let mut suppressions = Suppressions::new(db.source_line_index(src_id));
+    match path.extension() {
+        Some("js") | Some("ts") => {
+            let module = db.typescript_ast(src_id);
+            suppressions.add_comments(&module.syntax, TextUnit::default());
+            suppressions.close_all(TextUnit::of_str(&text));
+        }
+        Some("htm") | Some("html") => {
+            let document = db.html_ast(src_id);
+            suppressions.add_comments(&document.syntax, TextUnit::default());
+            suppressions.close_all(TextUnit::of_str(&text));
+        }
+        Some("vue") => {
+            let component = db.vue_ast(src_id);
+            suppressions.add_comments(&component.syntax, TextUnit::default());
+            suppressions.close_all(TextUnit::of_str(&text));
+
+            // N.B. the script's source is a single token in the component, so its comments are in the script's tree
+            if let Some((script_id, _)) = db.component_script(src_id) {
+                let script_pos = original_position(db, script_id, TextUnit::default()).map(|pos| pos.offset).unwrap_or_default();
+                let script = db.typescript_ast(script_id);
+                suppressions.add_comments(&script.syntax, script_pos);
+                suppressions.close_all(script_pos + TextUnit::of_str(&db.source_text(script_id)));
+            }
+        }
+        _ => (),
+    }
+    suppressions
+}
+
+#[derive(Debug)]
+pub(super) struct Suppressions {
+    line_index: Arc<LineIndex>,
+    items: Vec<Suppression>,
+    /// The indices of `vue-analyzer-disable` items which haven't been enabled yet.
+    open: Vec<usize>,
+}
+
+#[derive(Debug)]
+struct Suppression {
+    /// The comment containing the suppression.
+    comment: TextRange,
+    directive: &'static str,
+    covers: Covers,
+    /// The names of the suppressed rules, or empty if every rule is suppressed.
+    rules: Vec<String>,
+    used: bool,
+}
+
+#[derive(Debug)]
+enum Covers {
+    Line(u32),
+    Range(TextRange),
+}
+
+impl Suppressions {
+    fn new(line_index: Arc<LineIndex>) -> Suppressions {
+        Suppressions { line_index, items: Vec::new(), open: Vec::new() }
+    }
+
+    /// Adds the suppressions in the comments of a syntax tree, which starts at `base` in the checked file.
+    fn add_comments(&mut self, root: &SyntaxNode, base: TextUnit) {
+        for visit in root.preorder_with_tokens() {
+            match visit {
+                WalkEvent::Enter(SyntaxElement::Token(token)) if token.kind() == COMMENT => {
+                    self.add_comment(token.text().as_str(), token.range() + base);
+                }
+                _ => (),
+            }
+        }
+    }
+
+    fn add_comment(&mut self, text: &str, comment: TextRange) {
+        let (directive, rules) = match parse_directive(text) {
+            Some(parsed) => parsed,
+            None => return,
+        };
+        match directive {
+            DISABLE_NEXT_LINE => {
+                let line = self.line_index.line_col(comment.end()).line + 1;
+                self.items.push(Suppression { comment, directive, covers: Covers::Line(line), rules, used: false });
+            }
+            DISABLE => {
+                let covers = Covers::Range(TextRange::from_to(comment.end(), comment.end()));
+                self.open.push(self.items.len());
+                self.items.push(Suppression { comment, directive, covers, rules, used: false });
+            }
+            ENABLE => {
+                let mut closed = Vec::new();
+                let items = &mut self.items;
+                self.open.retain(|&idx| {
+                    let item = &mut items[idx];
+                    let covers = Covers::Range(TextRange::from_to(item.comment.end(), comment.start()));
+                    if rules.is_empty() || (!item.rules.is_empty() && item.rules.iter().all(|rule| rules.contains(rule))) {
+                        item.covers = covers;
+                        return false;
+                    }
+                    // N.B. only the enabled rules are closed, so the item's other rules are still disabled
+                    let (enabled, disabled) = item.rules.drain(..).partition::<Vec<_>, _>(|rule| rules.contains(rule));
+                    item.rules = disabled;
+                    if !enabled.is_empty() {
+                        let comment = item.comment;
+                        closed.push(Suppression { comment, directive: item.directive, covers, rules: enabled, used: false });
+                    }
+                    true
+                });
+                self.items.extend(closed);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Extends any `vue-analyzer-disable` comments without a matching `vue-analyzer-enable` to `end`.
+    fn close_all(&mut self, end: TextUnit) {
+        for idx in self.open.drain(..) {
+            let item = &mut self.items[idx];
+            item.covers = Covers::Range(TextRange::from_to(item.comment.end(), end));
+        }
+    }
+
+    /// Returns true if the diagnostic is covered by a suppression, marking the suppression as used.
+    pub(super) fn suppress(&mut self, diagnostic: &Diagnostic) -> bool {
+        let pos = match diagnostic.pos {
+            Some(pos) => pos,
+            None => return false,
+        };
+        let line = self.line_index.line_col(pos).line;
+        let mut suppressed = false;
+        for item in self.items.iter_mut() {
+            let covered = match item.covers {
+                Covers::Line(covered_line) => covered_line == line,
+                Covers::Range(range) => range.start() <= pos && pos <= range.end(),
+            };
+            if covered && (item.rules.is_empty() || item.rules.iter().any(|rule| rule == diagnostic.rule)) {
+                item.used = true;
+                suppressed = true;
+            }
+        }
+        suppressed
+    }
+
+    /// Returns a warning for each suppression comment that didn't suppress any diagnostics.
+    pub(super) fn unused(&self) -> Vec<Diagnostic> {
+        let mut results: Vec<Diagnostic> = Vec::new();
+        for item in self.items.iter() {
+            let pos = item.comment.start();
+            // N.B. a comment can have several items if some of its rules were enabled separately
+            let used = self.items.iter().any(|other| other.comment == item.comment && other.used);
+            if used || results.iter().any(|diag| diag.pos == Some(pos)) {
+                continue;
+            }
+            let message = format!("unused `{}` comment", item.directive);
+            results.push(Diagnostic::warn("suppression", pos, message));
+        }
+        results.sort_by_key(|diag| diag.pos);
+        results
+    }
+}
+
+fn parse_directive(text: &str) -> Option<(&'static str, Vec<String>)> {
+    let body = if text.starts_with("<!--") {
+        text[4..].trim_end_matches("-->")
+    } else if text.starts_with("//") {
+        &text[2..]
+    } else if text.starts_with("/*") {
+        text[2..].trim_end_matches("*/")
+    } else {
+        text
+    };
+    let mut words = body
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|word| !word.is_empty());
+    let directive = match words.next()? {
+        DISABLE_NEXT_LINE => DISABLE_NEXT_LINE,
+        DISABLE => DISABLE,
+        ENABLE => ENABLE,
+        _ => return None,
+    };
+    Some((directive, words.map(String::from).collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use typescript_grammar::ast as ts;
+
+    /// Suppresses a diagnostic at the start of each line for each `@style` or `@vue` in the line,
+    /// returning the rules of the diagnostics which weren't suppressed, and the unused warnings.
+    fn check(text: &str) -> (Vec<String>, Vec<String>) {
+        let (program, _) = ts::Program::parse(text);
+        let mut suppressions = Suppressions::new(Arc::new(LineIndex::new(text)));
+        suppressions.add_comments(&program.syntax, TextUnit::default());
+        suppressions.close_all(TextUnit::of_str(text));
+        let mut reported = Vec::new();
+        let mut offset = 0;
+        for line in text.split_inclusive('\n') {
+            for &rule in ["style", "vue"].iter().filter(|rule| line.contains(&format!("@{}", rule))) {
+                let diagnostic = Diagnostic::warn(rule, TextUnit::from(offset as u32), "");
+                if !suppressions.suppress(&diagnostic) {
+                    reported.push(rule.to_string());
+                }
+            }
+            offset += line.len();
+        }
+        let unused = suppressions.unused().into_iter().map(|diag| {
+            let line = suppressions.line_index.line_col(diag.pos.unwrap()).line;
+            format!("{}: {}", line, diag.message)
+        });
+        (reported, unused.collect())
+    }
+
+    #[test]
+    fn test_parse_directive() {
+        assert_eq!(parse_directive("<!-- vue-analyzer-disable-next-line -->"), Some((DISABLE_NEXT_LINE, vec![])));
+        assert_eq!(parse_directive("// vue-analyzer-disable-next-line vue"), Some((DISABLE_NEXT_LINE, vec!["vue".into()])));
+        assert_eq!(
+            parse_directive("/* vue-analyzer-disable style, pedantic */"),
+            Some((DISABLE, vec!["style".into(), "pedantic".into()])),
+        );
+        assert_eq!(parse_directive("/*vue-analyzer-enable*/"), Some((ENABLE, vec![])));
+        assert_eq!(parse_directive("// vue-analyzer-disabled"), None);
+        assert_eq!(parse_directive("<!-- just a comment -->"), None);
+    }
+
+    #[test]
+    fn test_disable_next_line() {
+        let text = "// vue-analyzer-disable-next-line vue\nx; // @vue @style\nx; // @vue\n";
+        assert_eq!(check(text), (vec!["style".into(), "vue".into()], vec![]));
+        let text = "// vue-analyzer-disable-next-line\nx;\nx; // @vue\n";
+        assert_eq!(check(text), (vec!["vue".into()], vec!["0: unused `vue-analyzer-disable-next-line` comment".into()]));
+    }
+
+    #[test]
+    fn test_disable_enable() {
+        let text = "/* vue-analyzer-disable */\nx; // @vue\n/* vue-analyzer-enable */\nx; // @vue\n";
+        assert_eq!(check(text), (vec!["vue".into()], vec![]));
+
+        // An unclosed disable covers the rest of the file, including a trailing line
+        let text = "x; // @vue\n// vue-analyzer-disable vue\nx; // @vue";
+        assert_eq!(check(text), (vec!["vue".into()], vec![]));
+
+        // Enabling some rules doesn't enable the other disabled rules
+        let text = "/* vue-analyzer-disable style, vue */\n/* vue-analyzer-enable style */\nx; // @vue @style\n";
+        assert_eq!(check(text), (vec!["style".into()], vec![]));
+        let text = "/* vue-analyzer-disable style, vue */\nx; // @style\n/* vue-analyzer-enable style */\nx; // @style\n";
+        assert_eq!(check(text), (vec!["style".into()], vec![]));
+
+        // Enabling a rule which isn't disabled doesn't close a disable of every rule
+        let text = "/* vue-analyzer-disable */\n/* vue-analyzer-enable style */\nx; // @vue\n";
+        assert_eq!(check(text), (vec![], vec![]));
+    }
+
+    #[test]
+    fn test_unused() {
+        let text = "/* vue-analyzer-disable style, vue */\nx;\n/* vue-analyzer-enable style */\nx;\n";
+        assert_eq!(check(text), (vec![], vec!["0: unused `vue-analyzer-disable` comment".into()]));
+        let text = "// vue-analyzer-disable-next-line style\nx; // @vue\n";
+        assert_eq!(check(text), (vec!["vue".into()], vec!["0: unused `vue-analyzer-disable-next-line` comment".into()]));
+    }
+}
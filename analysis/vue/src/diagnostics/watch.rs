@@ -0,0 +1,139 @@
+//! Checks the keys and handlers of a component's `watch` option.
+use super::Diagnostic;
+use crate::app::{get_object_property, string_value};
+use code_grammar::{AstNode, SmolStr, TextUnit};
+use typescript_analysis::ty::{infer_property_name, InterfaceTy, Ty};
+use typescript_grammar::ast as ts;
+
+pub(super) fn check_watchers(
+    vue_options: &ts::ObjectExpression,
+    vm: &InterfaceTy,
+    base: TextUnit,
+    results: &mut Vec<Diagnostic>,
+) {
+    let watch = match get_object_property(vue_options, "watch").and_then(ts::ObjectExpression::downcast) {
+        Some(watch) => watch,
+        None => return,
+    };
+    let mut methods: Vec<SmolStr> = get_object_property(vue_options, "methods")
+        .and_then(ts::ObjectExpression::downcast)
+        .map(|methods| methods.properties().filter_map(infer_property_name).collect())
+        .unwrap_or_default();
+    if get_object_property(vue_options, "extends").is_some() {
+        // N.B. inherited methods aren't known separately from the other inherited properties
+        methods.extend(vm.properties.iter().filter(|prop| prop.type_.as_function().is_some()).map(|prop| prop.ident.clone()));
+    }
+    for prop in watch.properties() {
+        let key = match infer_property_name(prop) {
+            Some(key) => key,
+            None => continue,
+        };
+        let pos = base + prop.syntax.range().start();
+        if !key.starts_with('$') && !is_watchable(vm, &key) {
+            let message = format!("watched property `{}` is not defined on the component", key);
+            results.push(Diagnostic::error("vue", pos, message));
+        }
+        if let Some(handler) = prop.value() {
+            check_handler(handler, &methods, &key, base, results);
+        }
+    }
+}
+
+/// Returns false if a watched path (e.g. `user.name`) definitely doesn't exist on the `vm`.
+fn is_watchable(vm: &InterfaceTy, path: &str) -> bool {
+    let mut object = vm;
+    for segment in path.split('.') {
+        let prop = match object.properties.iter().find(|prop| prop.ident == segment) {
+            Some(prop) => prop,
+            None => return false,
+        };
+        match &prop.type_ {
+            Ty::Interface(ty) => object = &**ty,
+            _ => return true, // N.B. the type of the value isn't precise enough to check further
+        }
+    }
+    true
+}
+
+fn check_handler(handler: &ts::Expression, methods: &[SmolStr], key: &str, base: TextUnit, results: &mut Vec<Diagnostic>) {
+    let pos = base + handler.syntax.range().start();
+    match handler.kind() {
+        ts::ExpressionKind::ArrowFunctionExpression(_) | ts::ExpressionKind::FunctionExpression(_) => (),
+        ts::ExpressionKind::Literal(_) => match string_value(handler) {
+            Some(method) => {
+                if !methods.contains(&method) {
+                    let message = format!("watch handler `{}` is not a method of the component", method);
+                    results.push(Diagnostic::error("vue", pos, message));
+                }
+            }
+            None => {
+                let message = format!("expected watcher for `{}` to be a function, method name or object", key);
+                results.push(Diagnostic::error("vue", pos, message));
+            }
+        },
+        ts::ExpressionKind::ObjectExpression(options) => match get_object_property(options, "handler") {
+            Some(handler) => check_handler(handler, methods, key, base, results),
+            None => {
+                let message = format!("watcher for `{}` is missing a `handler`", key);
+                results.push(Diagnostic::error("vue", pos, message));
+            }
+        },
+        ts::ExpressionKind::ArrayExpression(handlers) => {
+            for handler in handlers.elements() {
+                check_handler(handler, methods, key, base, results);
+            }
+        }
+        _ => (), // N.B. e.g. an imported function
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::component_options;
+
+    fn check(script: &str) -> Vec<String> {
+        let (program, _) = ts::Program::parse(script);
+        assert!(program.errors().is_empty(), "{:?}", program.errors());
+        let options = component_options(&program).unwrap();
+        let mut results = Vec::new();
+        let vm = super::super::infer_vm(options, TextUnit::default(), &mut results).unwrap();
+        check_watchers(options, &vm, TextUnit::default(), &mut results);
+        results.into_iter().map(|diag| diag.message).collect()
+    }
+
+    #[test]
+    fn test_watched_keys() {
+        let script = r#"export default {
+            props: ['value'],
+            data() { return { user: { name: '' } }; },
+            watch: { value() {}, 'user.name': function () {}, $route() {}, missing() {}, 'user.missing': () => {} }
+        }"#;
+        assert_eq!(check(script), vec![
+            "watched property `missing` is not defined on the component",
+            "watched property `user.missing` is not defined on the component",
+        ]);
+    }
+
+    #[test]
+    fn test_handlers() {
+        let script = r#"export default {
+            props: ['value'],
+            data() { return { count: 0, callback: () => {} }; },
+            computed: { total() { return 0; } },
+            methods: { refresh() {} },
+            watch: {
+                value: 'refresh',
+                count: ['refresh', 'total', { handler: 'callback' }],
+                total: { deep: true },
+                callback: 42
+            }
+        }"#;
+        assert_eq!(check(script), vec![
+            "watch handler `total` is not a method of the component",
+            "watch handler `callback` is not a method of the component",
+            "watcher for `total` is missing a `handler`",
+            "expected watcher for `callback` to be a function, method name or object",
+        ]);
+    }
+}
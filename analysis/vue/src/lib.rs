@@ -2,10 +2,12 @@ mod app;
 mod ast;
 mod config;
 mod diagnostics;
+mod module;
 
 pub use self::app::{AppDatabase, AppDatabaseStorage};
 pub use self::ast::{AstDatabase, AstDatabaseStorage};
 pub use self::config::{Config, ConfigDatabase, ConfigDatabaseStorage};
+pub use self::module::{ModuleAliases, ModuleDatabase, ModuleDatabaseStorage, ModuleExports};
 
 #[cfg(feature = "runtime")]
 mod debug;
@@ -18,6 +20,7 @@ pub trait VueDatabase:
     crate::AppDatabase +
     crate::AstDatabase +
     crate::ConfigDatabase +
+    crate::ModuleDatabase +
     code_analysis::SourceDatabase +
     html_analysis::AstDatabase +
     javascript_analysis::AstDatabase +
@@ -30,6 +33,7 @@ where
     T: crate::AppDatabase +
        crate::AstDatabase +
        crate::ConfigDatabase +
+       crate::ModuleDatabase +
        code_analysis::SourceDatabase +
        html_analysis::AstDatabase +
        javascript_analysis::AstDatabase +
@@ -0,0 +1,391 @@
+use crate::{AstDatabase, ConfigDatabase};
+use code_analysis::{FileId, RelativePath, RelativePathBuf, SourceDatabase, SourceRoot, SourceRootId};
+use code_grammar::{AstNode, SmolStr};
+use typescript_grammar::ast as ts;
+use rustc_hash::FxHashSet;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+#[salsa::query_group(ModuleDatabaseStorage)]
+pub trait ModuleDatabase: AstDatabase + ConfigDatabase + SourceDatabase + typescript_analysis::AstDatabase {
+    /// Resolves an import specifier (e.g. `./Foo.vue`, `@/components/Foo` or `lodash`) from a file.
+    fn resolve_import(&self, file_id: FileId, specifier: SmolStr) -> Option<FileId>;
+
+    /// The names exported by a module, not including `export * from '...'` re-exports.
+    fn module_exports(&self, file_id: FileId) -> Arc<ModuleExports>;
+
+    /// Path aliases of a local source root, from its `tsconfig.json` (or `jsconfig.json`) and config.
+    fn module_aliases(&self, root: SourceRootId) -> Arc<ModuleAliases>;
+}
+
+/// Extensions which are tried (in order) when an import doesn't match a file exactly.
+const EXTENSIONS: &[&str] = &["ts", "js", "vue", "json"];
+
+pub fn resolve_import(db: &impl ModuleDatabase, file_id: FileId, specifier: SmolStr) -> Option<FileId> {
+    let root_id = db.file_source_root(file_id);
+    let root = db.source_root(root_id);
+    let specifier = specifier.as_str();
+    if specifier == "." || specifier == ".." || specifier.starts_with("./") || specifier.starts_with("../") {
+        let path = db.file_relative_path(file_id);
+        let dir = path.parent().unwrap_or_else(|| RelativePath::new(""));
+        return resolve_path(db, &root, &dir.join(specifier));
+    } else if specifier.starts_with('/') {
+        return None;
+    }
+
+    // Aliases (e.g. `@/components/Foo`) are only configured for the local project
+    if db.local_roots().contains(&root_id) {
+        let aliases = db.module_aliases(root_id);
+        for candidate in aliases.expand(specifier) {
+            if let Some(file_id) = resolve_path(db, &root, &candidate) {
+                return Some(file_id);
+            }
+        }
+    }
+
+    // Otherwise the import is a package from `node_modules`
+    for foreign_id in db.foreign_roots().iter() {
+        let foreign = db.source_root(*foreign_id);
+        if let Some(file_id) = resolve_path(db, &foreign, RelativePath::new(specifier)) {
+            return Some(file_id);
+        }
+    }
+    None
+}
+
+pub fn module_exports(db: &impl ModuleDatabase, file_id: FileId) -> Arc<ModuleExports> {
+    let mut exports = ModuleExports::default();
+    let path = db.file_relative_path(file_id);
+    let src_id = match path.extension() {
+        Some("js") | Some("ts") => db.file_source(file_id),
+        Some("vue") => {
+            // N.B. the component is always the default export, even without a script
+            exports.names.insert("default".into());
+            match db.component_script(db.file_source(file_id)) {
+                Some((id, _)) => id,
+                None => return Arc::new(exports),
+            }
+        }
+        _ => {
+            // TODO: Support `.json` modules
+            exports.complete = false;
+            return Arc::new(exports);
+        }
+    };
+    let program = db.typescript_ast(src_id);
+    if !program.errors().is_empty() {
+        exports.complete = false;
+        return Arc::new(exports);
+    }
+
+    let mut is_module = false;
+    for decl in program.syntax.children().filter_map(ts::ModuleDeclaration::cast) {
+        match decl.kind() {
+            ts::ModuleDeclarationKind::ExportDefaultDeclaration(_) => {
+                exports.names.insert("default".into());
+            }
+            ts::ModuleDeclarationKind::ExportNamedDeclaration(export) => {
+                if let Some(decl) = export.declaration().and_then(|decl| ts::VariableDeclaration::downcast(decl)) {
+                    for pattern in decl.declarations().filter_map(ts::VariableDeclarator::id) {
+                        collect_pattern_names(pattern, &mut exports.names);
+                    }
+                } else if export.specifiers().next().is_some() {
+                    exports.names.extend(export.specifiers().map(|spec| SmolStr::from(spec.exported().name())));
+                } else {
+                    // e.g. `export function foo() {}` or `export interface Foo {}`
+                    let ident = export.syntax.first_child().and_then(|decl| decl.children().find_map(ts::Identifier::cast));
+                    exports.names.extend(ident.map(|ident| SmolStr::from(ident.name())));
+                }
+            }
+            ts::ModuleDeclarationKind::ExportAllDeclaration(export) => {
+                let target = export.source()
+                    .and_then(module_specifier)
+                    .and_then(|specifier| db.resolve_import(file_id, specifier));
+                match target {
+                    Some(target) => exports.reexports.push(target),
+                    None => exports.complete = false,
+                }
+            }
+            ts::ModuleDeclarationKind::ImportDeclaration(_) => (),
+        }
+        is_module = true;
+    }
+    if !is_module && path.extension() != Some("vue") {
+        // N.B. probably a CommonJS module (e.g. `module.exports = ...`)
+        exports.complete = false;
+    }
+    Arc::new(exports)
+}
+
+fn collect_pattern_names(pattern: &ts::Pattern, names: &mut FxHashSet<SmolStr>) {
+    match pattern.kind() {
+        ts::PatternKind::Identifier(ident) => {
+            names.insert(ident.name().into());
+        }
+        ts::PatternKind::ObjectPattern(obj) => {
+            for pattern in obj.properties().filter_map(ts::AssignmentProperty::value) {
+                collect_pattern_names(pattern, names);
+            }
+        }
+        ts::PatternKind::ArrayPattern(arr) => {
+            for pattern in arr.elements().filter_map(|el| el) {
+                collect_pattern_names(pattern, names);
+            }
+        }
+        ts::PatternKind::AssignmentPattern(_) | ts::PatternKind::RestElement(_) => {
+            if let Some(pattern) = pattern.syntax.first_child().and_then(ts::Pattern::cast) {
+                collect_pattern_names(pattern, names);
+            }
+        }
+        ts::PatternKind::MemberExpression(_) => (),
+    }
+}
+
+/// Returns whether a module exports `name`, following any `export * from '...'` re-exports.
+///
+/// Returns `None` if the module's exports can't be completely determined.
+pub(crate) fn is_exported(db: &impl ModuleDatabase, file_id: FileId, name: &str) -> Option<bool> {
+    let mut visited = FxHashSet::default();
+    let mut stack = vec![file_id];
+    let mut complete = true;
+    while let Some(file_id) = stack.pop() {
+        if !visited.insert(file_id) {
+            continue; // N.B. modules may re-export each other
+        }
+        let exports = db.module_exports(file_id);
+        if exports.names.contains(name) {
+            return Some(true);
+        }
+        complete &= exports.complete;
+
+        // N.B. `export *` doesn't re-export the default export
+        if name != "default" {
+            stack.extend(exports.reexports.iter().cloned());
+        }
+    }
+    if complete { Some(false) } else { None }
+}
+
+/// Gets the module specifier from the string literal of an import or export.
+pub(crate) fn module_specifier(literal: &ts::Literal) -> Option<SmolStr> {
+    match literal.kind() {
+        ts::LiteralKind::String(tok) => {
+            let raw = tok.text().as_str();
+            unescape::unescape(&raw[1 .. raw.len() - 1]).map(SmolStr::from)
+        }
+        _ => None,
+    }
+}
+
+/// Resolves a path relative to the source root as either a file or a directory.
+fn resolve_path(db: &impl ModuleDatabase, root: &SourceRoot, path: &RelativePath) -> Option<FileId> {
+    let path = path.normalize();
+    if path.as_str().starts_with("..") {
+        return None; // N.B. files outside of the source root can't be found by path
+    }
+    if let Some(file_id) = resolve_file(root, &path) {
+        return Some(file_id);
+    }
+    if let Some(manifest_id) = root.files.get(&path.join("package.json")) {
+        let entry = package_entry(&db.file_text(*manifest_id)).map(|entry| path.join(entry).normalize());
+        if let Some(file_id) = entry.and_then(|entry| resolve_file(root, &entry).or_else(|| resolve_index(root, &entry))) {
+            return Some(file_id);
+        }
+    }
+    resolve_index(root, &path)
+}
+
+fn resolve_file(root: &SourceRoot, path: &RelativePath) -> Option<FileId> {
+    if let Some(file_id) = root.files.get(path) {
+        return Some(*file_id);
+    }
+    EXTENSIONS.iter().find_map(|ext| {
+        let candidate = RelativePathBuf::from(format!("{}.{}", path.as_str(), ext));
+        root.files.get(&candidate).cloned()
+    })
+}
+
+fn resolve_index(root: &SourceRoot, path: &RelativePath) -> Option<FileId> {
+    EXTENSIONS.iter().find_map(|ext| root.files.get(&path.join(format!("index.{}", ext))).cloned())
+}
+
+/// Finds the entrypoint of a package from its `package.json`, preferring `module` over `main`.
+fn package_entry(text: &str) -> Option<String> {
+    let manifest: serde_json::Value = serde_json::from_str(text).ok()?;
+    ["module", "main"].iter().find_map(|key| manifest.get(key)?.as_str().map(String::from))
+}
+
+pub fn module_aliases(db: &impl ModuleDatabase, root_id: SourceRootId) -> Arc<ModuleAliases> {
+    let root = db.source_root(root_id);
+    let mut aliases = ModuleAliases::default();
+
+    // Read `compilerOptions.baseUrl` and `compilerOptions.paths`
+    let tsconfig = root.files.get(RelativePath::new("tsconfig.json"))
+        .or_else(|| root.files.get(RelativePath::new("jsconfig.json")));
+    let options = tsconfig
+        .and_then(|file_id| serde_json::from_str::<TsConfig>(&strip_json_comments(&db.file_text(*file_id))).ok())
+        .map(|tsconfig| tsconfig.compiler_options)
+        .unwrap_or_default();
+    let base_url = options.base_url.map(|url| RelativePath::new(&url).normalize());
+    for (pattern, targets) in options.paths {
+        let base = base_url.clone().unwrap_or_else(|| RelativePathBuf::from(String::new()));
+        let targets = targets.iter().map(|target| base.join(target).normalize().as_str().to_string()).collect();
+        aliases.paths.push((pattern, targets));
+    }
+    aliases.base_url = base_url;
+
+    // Aliases from the config behave like webpack's `resolve.alias` (e.g. `"@" = "src"`)
+    let config = db.vue_config(root_id);
+    for (alias, target) in &config.resolve.alias {
+        aliases.paths.push((alias.clone(), vec![target.clone()]));
+        aliases.paths.push((format!("{}/*", alias), vec![format!("{}/*", target)]));
+    }
+
+    // Prefer the most specific pattern, as typescript does
+    aliases.paths.sort_by_key(|(pattern, _)| std::cmp::Reverse(pattern.find('*').unwrap_or(pattern.len())));
+    Arc::new(aliases)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ModuleExports {
+    /// The exported names, including `default` for the default export.
+    pub names: FxHashSet<SmolStr>,
+    /// The modules re-exported by `export * from '...'`.
+    pub reexports: Vec<FileId>,
+    /// Whether every export of the module could be determined.
+    pub complete: bool,
+}
+
+impl Default for ModuleExports {
+    fn default() -> ModuleExports {
+        ModuleExports { names: FxHashSet::default(), reexports: Vec::new(), complete: true }
+    }
+}
+
+/// Path aliases in the style of `tsconfig.json`'s `compilerOptions.paths`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ModuleAliases {
+    base_url: Option<RelativePathBuf>,
+    /// Patterns (e.g. `@/*`) with at most one `*`, and the paths substituted for them (e.g. `src/*`).
+    ///
+    /// N.B. unlike in `tsconfig.json`, paths are relative to the source root.
+    paths: Vec<(String, Vec<String>)>,
+}
+
+impl ModuleAliases {
+    /// Returns the candidate paths of an import specifier, in order of precedence.
+    pub fn expand(&self, specifier: &str) -> Vec<RelativePathBuf> {
+        let mut candidates = Vec::new();
+        for (pattern, targets) in &self.paths {
+            let matched = match pattern.find('*') {
+                Some(idx) => {
+                    let (prefix, suffix) = (&pattern[..idx], &pattern[idx + 1..]);
+                    if specifier.len() >= prefix.len() + suffix.len() &&
+                        specifier.starts_with(prefix) &&
+                        specifier.ends_with(suffix)
+                    {
+                        Some(&specifier[prefix.len() .. specifier.len() - suffix.len()])
+                    } else {
+                        None
+                    }
+                }
+                None if pattern == specifier => Some(""),
+                None => None,
+            };
+            if let Some(matched) = matched {
+                candidates.extend(targets.iter().map(|target| RelativePathBuf::from(target.replacen('*', matched, 1))));
+            }
+        }
+        if let Some(base_url) = &self.base_url {
+            candidates.push(base_url.join(specifier));
+        }
+        candidates
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct TsConfig {
+    #[serde(default, rename = "compilerOptions")]
+    compiler_options: TsCompilerOptions,
+}
+
+#[derive(Default, serde::Deserialize)]
+struct TsCompilerOptions {
+    #[serde(default, rename = "baseUrl")]
+    base_url: Option<String>,
+    #[serde(default)]
+    paths: BTreeMap<String, Vec<String>>,
+}
+
+/// Removes the comments from a `tsconfig.json`, which (unlike json) may contain them.
+fn strip_json_comments(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut in_string = false;
+    while let Some(c) = chars.next() {
+        if in_string {
+            stripped.push(c);
+            match c {
+                '\\' => stripped.extend(chars.next()),
+                '"' => in_string = false,
+                _ => (),
+            }
+        } else if c == '/' && chars.peek() == Some(&'/') {
+            while chars.peek().map(|&c| c != '\n').unwrap_or(false) {
+                chars.next();
+            }
+        } else if c == '/' && chars.peek() == Some(&'*') {
+            chars.next();
+            let mut prev = ' ';
+            while let Some(c) = chars.next() {
+                if prev == '*' && c == '/' {
+                    break;
+                }
+                prev = c;
+            }
+        } else {
+            in_string = c == '"';
+            stripped.push(c);
+        }
+    }
+    stripped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_aliases() {
+        let aliases = ModuleAliases {
+            base_url: Some("src".into()),
+            paths: vec![
+                ("@components/*".into(), vec!["src/components/*".into()]),
+                ("@/*".into(), vec!["src/*".into()]),
+                ("@".into(), vec!["src".into()]),
+            ],
+        };
+        assert_eq!(aliases.expand("@/views/Home"), vec![
+            RelativePathBuf::from("src/views/Home"),
+            RelativePathBuf::from("src/@/views/Home"),
+        ]);
+        assert_eq!(aliases.expand("@components/Foo.vue"), vec![
+            RelativePathBuf::from("src/components/Foo.vue"),
+            RelativePathBuf::from("src/@components/Foo.vue"),
+        ]);
+        assert_eq!(aliases.expand("lodash"), vec![RelativePathBuf::from("src/lodash")]);
+    }
+
+    #[test]
+    fn test_strip_json_comments() {
+        let text = r#"{
+            // comment
+            "baseUrl": "./src", /* "paths": {} */
+            "url": "http://example.com/*"
+        }"#;
+        let value: serde_json::Value = serde_json::from_str(&strip_json_comments(text)).unwrap();
+        assert_eq!(value["baseUrl"], "./src");
+        assert_eq!(value["url"], "http://example.com/*");
+        assert!(value.get("paths").is_none());
+    }
+}
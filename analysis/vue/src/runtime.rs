@@ -9,6 +9,7 @@ use std::sync::Arc;
 
 use crate::AstDatabase as _;
 use crate::ConfigDatabase as _;
+use crate::ModuleDatabase as _;
 use code_analysis::SourceDatabase as _;
 use html_analysis::AstDatabase as _;
 use javascript_analysis::AstDatabase as _;
@@ -17,6 +18,7 @@ use typescript_analysis::AstDatabase as _;
 use crate::AppDatabaseStorage as VueAppStorage;
 use crate::AstDatabaseStorage as VueAstStorage;
 use crate::ConfigDatabaseStorage as VueConfigStorage;
+use crate::ModuleDatabaseStorage as VueModuleStorage;
 use code_analysis::SourceDatabaseStorage as SourceStorage;
 use html_analysis::AstDatabaseStorage as HtmlAstStorage;
 use javascript_analysis::AstDatabaseStorage as JsAstStorage;
@@ -28,6 +30,7 @@ use typescript_analysis::AstDatabaseStorage as TsAstStorage;
     VueAppStorage,
     VueAstStorage,
     VueConfigStorage,
+    VueModuleStorage,
     JsAstStorage,
     TsAstStorage,
 )]
@@ -54,6 +57,29 @@ impl Default for HostDatabase {
     }
 }
 
+#[cfg(test)]
+impl HostDatabase {
+    /// Creates a database with several files in a single source root, for tests.
+    pub(crate) fn with_files(files: &[(&str, &str)]) -> (HostDatabase, Vec<FileId>) {
+        let source_root = SourceRootId(0);
+        let mut db = HostDatabase::default();
+        let mut packages = PackageGraph::default();
+        packages.add_package_root(FileId(0));
+        let mut change = SourceChange::new();
+        change.add_root(source_root, true);
+        let mut file_ids = Vec::new();
+        for (idx, (path, text)) in files.iter().enumerate() {
+            let file_id = FileId(idx as u32);
+            change.add_file(source_root, file_id, (*path).into(), Arc::new(text.to_string()));
+            file_ids.push(file_id);
+        }
+        change.set_package_graph(packages);
+        change.apply_to(&mut db);
+        db.set_vue_config(source_root, Arc::new(Config::default()));
+        (db, file_ids)
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct Analysis {
     db: HostDatabase,
@@ -137,6 +163,11 @@ impl Analysis {
         crate::diagnostics::check(&self.db, file_id)
     }
 
+    /// Resolves an import specifier (e.g. `./Foo.vue` or `lodash`) from the given file.
+    pub fn resolve_import(&self, file_id: FileId, specifier: &str) -> Option<FileId> {
+        self.db.resolve_import(file_id, specifier.into())
+    }
+
     /// Gets the list of files in the source root
     pub fn files(&self, root_id: SourceRootId) -> impl Iterator<Item = (RelativePathBuf, FileId)> {
         self.db.source_root(root_id).files.clone().into_iter()
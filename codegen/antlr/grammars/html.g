@@ -40,7 +40,7 @@ doctype
     ;
 
 elements
-    : html_misc* element html_misc*
+    : html_misc* (element | stray_end_tag) html_misc*
     ;
 
 element
@@ -49,9 +49,17 @@ element
     ;
 
 
+// N.B. implemented by hand in `grammar_ext.rs`, because whether an end tag can be omitted depends
+//      on the element's tag name and on the tags which follow it (e.g. `<ul><li>a<li>b</ul>`);
+//      an omitted end tag is recorded as an empty `IMPLIED_END_TAG` node
+#[external]
 element_pattern
     : '<' empty_element_tag_name WS? (attribute WS?)* ('>' | '/>')
-    | '<' TAG_NAME WS? (attribute WS?)* ('/>' | '>' html_content ('<' '/' | '</') WS? TAG_NAME WS? '>')
+    | '<' TAG_NAME WS? (attribute WS?)* ('/>' | '>' html_content_item* end_tag?)
+    ;
+
+end_tag
+    : ('<' '/' | '</') WS? TAG_NAME WS? '>'
     ;
 
 empty_element_tag_name
@@ -81,10 +89,44 @@ attribute_value
     | TAG_NAME
     ;
 
-html_content
-    : html_chardata? ((element | COMMENT) html_chardata?)*
-    | script_block?
-    | style_block?
+html_content_item
+    : element
+    | void_end_tag
+    | stray_end_tag
+    | COMMENT
+    | html_chardata
+    | script_block
+    | style_block
+    ;
+
+// N.B. void elements can't have an end tag, but browsers ignore it (or treat `</br>` as `<br>`)
+void_end_tag
+    : void_end_tag_open TAG_NAME WS? '>'
+    # VOID_END_TAG
+    ;
+
+// N.B. browsers treat a `</p>` without a start tag as an empty `<p>` (e.g. when the `<p>` is
+//      implicitly closed by the `<div>` in `<p><div></div></p>`), and ignore other end tags
+stray_end_tag
+    : {nth_at_keyword(1, "p")}? '</' TAG_NAME WS? '>'
+    # STRAY_END_TAG
+    ;
+
+void_end_tag_open
+    : {nth_at_keyword(1, "area")}? '</'
+    | {nth_at_keyword(1, "base")}? '</'
+    | {nth_at_keyword(1, "br")}? '</'
+    | {nth_at_keyword(1, "col")}? '</'
+    | {nth_at_keyword(1, "embed")}? '</'
+    | {nth_at_keyword(1, "hr")}? '</'
+    | {nth_at_keyword(1, "img")}? '</'
+    | {nth_at_keyword(1, "input")}? '</'
+    | {nth_at_keyword(1, "link")}? '</'
+    | {nth_at_keyword(1, "meta")}? '</'
+    | {nth_at_keyword(1, "param")}? '</'
+    | {nth_at_keyword(1, "source")}? '</'
+    | {nth_at_keyword(1, "track")}? '</'
+    | {nth_at_keyword(1, "wbr")}? '</'
     ;
 
 html_chardata
@@ -160,7 +160,9 @@ empty_statement
     ;
 
 expression_statement
-    : {!at(L_CURLY) && !at(FUNCTION_KW)}? expression_list end_of_statement
+    : {!at(L_CURLY) && !at(FUNCTION_KW) && !at(IMPORT_KW)}? expression_list end_of_statement
+    # EXPRESSION_STATEMENT
+    | {at(IMPORT_KW) && nth_at(1, L_PAREN)}? expression_list end_of_statement
     # EXPRESSION_STATEMENT
     ;
 
@@ -529,6 +531,7 @@ expression
     | IDENTIFIER                                           # IDENTIFIER  // convert to node
     | THIS_KW                                              # THIS_EXPRESSION
     | SUPER_KW                                             # SUPER_EXPRESSION
+    | IMPORT_KW                                            # IMPORT_EXPRESSION
     | AWAIT_KW expression                                  # AWAIT_EXPRESSION
     | YIELD_KW '*'? expression                             # YIELD_EXPRESSION
     | literal
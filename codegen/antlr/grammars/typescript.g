@@ -108,6 +108,7 @@ expression
     | IDENTIFIER                                           # IDENTIFIER  // convert to node
     | THIS_KW                                              # THIS_EXPRESSION
     | SUPER_KW                                             # SUPER_EXPRESSION
+    | IMPORT_KW                                            # IMPORT_EXPRESSION
     | AWAIT_KW expression                                  # AWAIT_EXPRESSION
     | YIELD_KW '*'? expression                             # YIELD_EXPRESSION
     | literal
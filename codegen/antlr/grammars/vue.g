@@ -41,20 +41,39 @@ style_tag
     ;
 
 template_content
-    : html_chardata? ((element | MUSTACHE | COMMENT) html_chardata?)*
+    : template_content_item*
     ;
 
-html_content
-    : html_chardata? ((element | MUSTACHE | COMMENT) html_chardata?)*
-    | script_block?
-    | style_block?
+template_content_item
+    : element
+    | void_end_tag
+    | stray_end_tag
+    | MUSTACHE
+    | COMMENT
+    | html_chardata
+    ;
+
+html_content_item
+    : element
+    | void_end_tag
+    | stray_end_tag
+    | MUSTACHE
+    | COMMENT
+    | html_chardata
+    | script_block
+    | style_block
     ;
 
 attribute
     : ({at_keyword("v-bind")}? TAG_NAME)? ':' attribute_key attribute_modifier* (WS? '=' WS? attribute_value)?
     # ATTRIBUTE_BINDING
-    | ({at_keyword("v-on")}? TAG_NAME | '@') attribute_key attribute_modifier* (WS? '=' WS? attribute_value)?
+    | ({at_keyword("v-slot")}? TAG_NAME ':' | '#') attribute_key (WS? '=' WS? attribute_value)?
+    # ATTRIBUTE_SLOT
+    | ({at_keyword("v-on")}? TAG_NAME ':' | '@') attribute_key attribute_modifier* (WS? '=' WS? attribute_value)?
     # ATTRIBUTE_LISTENER
+    // N.B. a custom directive's argument (e.g. `v-my-dir:arg.mod`)
+    | TAG_NAME ':' attribute_key attribute_modifier* (WS? '=' WS? attribute_value)?
+    # ATTRIBUTE
     | TAG_NAME WS? '=' WS? attribute_value
     # ATTRIBUTE
     | TAG_NAME
@@ -66,8 +85,13 @@ attribute_modifier
     # ATTRIBUTE_MODIFIER
     ;
 
+// N.B. a dynamic argument is parsed as a javascript expression later, but html doesn't allow `=`, `>` or `/>`
+attribute_key_token
+    : WS | TAG_NAME | QUOTED | ERROR | '(' | ')' | '{' | '}' | '.' | ':' | '@' | '~' | '!' | '$' | '%' | '^' | '|' | '&' | '*' | '?' | '/'
+    ;
+
 attribute_key
-    : '[' WS? TAG_NAME  WS? ']'
+    : '[' attribute_key_token* ']'
     # ATTRIBUTE_KEY
     | TAG_NAME
     # ATTRIBUTE_KEY
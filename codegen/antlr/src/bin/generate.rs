@@ -70,7 +70,7 @@ use code_grammar::parser::Continue;
 
         // Import any rules that must be written by hand
         let lr_rules = db.grammar().rules.iter()
-            .filter(|r| db.is_left_recursive(&r.name))
+            .filter(|r| db.is_left_recursive(&r.name) || db.is_external(&r.name))
             .map(|r| &r.name)
             .cloned()
             .collect::<Vec<_>>();
@@ -125,6 +125,9 @@ fn emit_grammar(out: &mut String, db: &Database) {
             eprintln!("warn: left recursive rule '{}' must be implemented by hand in 'grammar_ext.rs'", rule.name);
             continue;
         }
+        if db.is_external(&rule.name) {
+            continue;
+        }
 
         out.push('\n');
         if rule.name.starts_with("_") {
@@ -872,13 +875,14 @@ fn emit_predicate_description(out: &mut String, expr: &PredicateExpression) {
         PredicateExpression::Call { method, args } => {
             out.push_str(&method.replace("_", " "));
             for (i, arg) in args.iter().enumerate() {
+                let arg = arg.replace("\\", "\\\\").replace("\"", "'");
                 if i > 0 {
                     out.push_str(" (with ");
                     out.push_str(&arg);
                     out.push(')');
                 } else {
                     out.push(' ');
-                    out.push_str(&arg.replace("\\", "\\\\").replace("\"", "'"));
+                    out.push_str(&arg);
                 }
             }
         }
@@ -74,6 +74,14 @@ impl Database {
         )
     }
 
+    /// If the rule is marked with `#[external]`, so it must be implemented by hand
+    pub fn is_external(&self, rule: &str) -> bool {
+        self.rule(rule).attributes.iter().any(|attr| match attr {
+            Attribute::Word(word) => word == "external",
+            Attribute::Group(..) => false,
+        })
+    }
+
     pub fn is_left_recursive(&self, rule: &str) -> bool {
         self.is_left_corner(rule, rule)
     }
@@ -676,6 +684,8 @@ pub fn to_token_of_literal(lit: &str) -> &'static str {
         "^" => "CARET",
         "^=" => "CARET_EQ",
         "@" => "AT",
+        "#" => "HASH",
+        "$" => "DOLLAR",
         "~" => "TILDE",
         "?" => "QUESTION",
         "->" => "THIN_ARROW",
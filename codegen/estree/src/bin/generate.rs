@@ -15,6 +15,7 @@ fn main() -> Result<(), std::io::Error> {
         "codegen/estree/spec/es2017.md",
         "codegen/estree/spec/es2018.md",
         "codegen/estree/spec/es2019.md",
+        "codegen/estree/spec/experimental/import-expression.md",
         "codegen/estree/spec/extensions/type-annotations.md",
         "codegen/estree/contrib/typescript.md",
     ];
@@ -92,6 +93,8 @@ fn main() -> Result<(), std::io::Error> {
         out.push_str(", ");
         if node.name == "Super" {
             out.push_str("SUPER_EXPRESSION");
+        } else if node.name == "Import" {
+            out.push_str("IMPORT_EXPRESSION");
         } else if node.name == "TemplateLiteral" {
             out.push_str("TEMPLATE_EXPRESSION");
         } else {
@@ -127,6 +130,8 @@ fn main() -> Result<(), std::io::Error> {
         out.push_str("            ");
         if node.name == "Super" {
             out.push_str("SUPER_EXPRESSION");
+        } else if node.name == "Import" {
+            out.push_str("IMPORT_EXPRESSION");
         } else if node.name == "TemplateLiteral" {
             out.push_str("TEMPLATE_EXPRESSION");
         } else {
@@ -196,6 +201,8 @@ fn try_emit_enum(out: &mut String, spec: &Tree, node: &ast::Interface) -> bool {
                     out.push_str(" = ");
                     if child.name == "Super" {
                         out.push_str("SUPER_EXPRESSION");
+                    } else if child.name == "Import" {
+                        out.push_str("IMPORT_EXPRESSION");
                     } else if child.name == "TemplateLiteral" {
                         out.push_str("TEMPLATE_EXPRESSION");
                     } else {
@@ -216,6 +223,8 @@ fn try_emit_enum(out: &mut String, spec: &Tree, node: &ast::Interface) -> bool {
                                 out.push_str(" = ");
                                 if child.name == "Super" {
                                     out.push_str("SUPER_EXPRESSION");
+                                } else if child.name == "Import" {
+                                    out.push_str("IMPORT_EXPRESSION");
                                 } else if child.name == "TemplateLiteral" {
                                     out.push_str("TEMPLATE_EXPRESSION");
                                 } else {
@@ -22,6 +22,7 @@ impl Document {
             max_rollback_size: 4,
             preserve_comments: true,
             preserve_whitespace: true,
+            keywords_ignore_case: true,
         });
         let (root, remainder) = parser.parse(grammar::document);
         let node = Document::new(root.to_owned());
@@ -43,4 +44,63 @@ impl Script {
     pub fn source(&self) -> Option<SyntaxToken> {
         self.syntax.first_token()
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax_kind::{ELEMENT, IMPLIED_END_TAG, STRAY_END_TAG, VOID_END_TAG};
+    use code_grammar::SyntaxNodeExt;
+
+    #[test]
+    fn test_void_end_tags() {
+        let (document, _) = Document::parse("<div><br></br><p>text</p></div>");
+        assert!(document.errors().is_empty(), "{:?}", document.errors());
+        let void_end_tags = document.syntax.descendants().filter(|node| node.kind() == VOID_END_TAG).count();
+        assert_eq!(void_end_tags, 1);
+    }
+
+    #[test]
+    fn test_implied_end_tags() {
+        let implied_end_tags = |text: &str| {
+            let (document, _) = Document::parse(text);
+            assert!(document.errors().is_empty(), "{:?}", document.errors());
+            document.syntax.descendants().filter(|node| node.kind() == IMPLIED_END_TAG).count()
+        };
+        assert_eq!(implied_end_tags("<ul><li>a<li>b</ul>"), 2);
+        assert_eq!(implied_end_tags("<div><p>x<div></div></div>"), 1);
+        assert_eq!(implied_end_tags("<dl><dt>a<dd>b<dt>c</dl>"), 3);
+        assert_eq!(implied_end_tags("<table><tr><td>a<td>b<tr><th>c</table>"), 5);
+        assert_eq!(implied_end_tags("<select><option>a<option>b</option></select>"), 1);
+        assert_eq!(implied_end_tags("<html><head><title>a</title><body><p>b</html>"), 3);
+        assert_eq!(implied_end_tags("<div><p>a</p><span>b</span></div>"), 0);
+        assert_eq!(implied_end_tags("<UL><LI>a<LI>b</UL>"), 2);
+        assert_eq!(implied_end_tags("<div><p>x</P></div>"), 0);
+
+        // Elements without an optional end tag must still be closed
+        let (document, _) = Document::parse("<div><span>a</div>");
+        assert!(!document.errors().is_empty());
+    }
+
+    #[test]
+    fn test_implied_end_tag_siblings() {
+        let (document, _) = Document::parse("<ul><li>a<li>b</ul>");
+        let list = document.syntax.descendants().find(|node| node.kind() == ELEMENT).unwrap();
+        let items = list.children().filter(|node| node.kind() == ELEMENT).count();
+        assert_eq!(items, 2);
+    }
+
+    #[test]
+    fn test_stray_end_tags() {
+        let stray_end_tags = |text: &str| {
+            let (document, _) = Document::parse(text);
+            assert!(document.errors().is_empty(), "{:?}", document.errors());
+            document.syntax.descendants().filter(|node| node.kind() == STRAY_END_TAG).count()
+        };
+        assert_eq!(stray_end_tags("<p><div></div></p>"), 1);
+        assert_eq!(stray_end_tags("<section><p>text<div>block</div></p><p>a</p></section>"), 1);
+        assert_eq!(stray_end_tags("<div></p></div>"), 1);
+        assert_eq!(stray_end_tags("<p><span>a</span></p><p>b</p>"), 0);
+        assert_eq!(stray_end_tags("<p><span>a</p>"), 0);
+        assert_eq!(stray_end_tags("<P>a</p><div></P></div>"), 1);
+    }
+}
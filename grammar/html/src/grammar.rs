@@ -12,6 +12,8 @@ use crate::syntax_kind::*;
 use code_grammar::{catch, tokenset, Parser, TokenSet};
 use code_grammar::parser::Continue;
 
+pub use crate::grammar_ext::element_pattern;
+
 pub fn document(p: &mut Parser) -> Option<Continue> {
     let _marker = p.start();
     let _ok = catch!({
@@ -20,7 +22,7 @@ pub fn document(p: &mut Parser) -> Option<Continue> {
             doctype(p)?;
         }
         p.eat(WS);
-        while p.at_ts(&tokenset![COMMENT, L_ANGLE, TEXT, WHITESPACE]) {
+        while p.at_ts(&tokenset![COMMENT, L_ANGLE, TEXT, WHITESPACE]) || (p.nth_at_keyword(1, "p") && p.at(L_ANGLE_SLASH)) {
             let _checkpoint = p.checkpoint_ambiguous();
             elements(p);
             if !p.commit(_checkpoint)?.is_ok() {
@@ -60,7 +62,13 @@ pub fn elements(p: &mut Parser) -> Option<Continue> {
     while p.at_ts(&tokenset![COMMENT, TEXT, WHITESPACE]) {
         html_misc(p)?;
     }
-    element(p)?;
+    if p.at(L_ANGLE) {
+        element(p)?;
+    } else if p.nth_at_keyword(1, "p") && p.at(L_ANGLE_SLASH) {
+        stray_end_tag(p)?;
+    } else {
+        p.expected_ts_in("elements", &tokenset![L_ANGLE, L_ANGLE_SLASH])?;
+    }
     while p.at_ts(&tokenset![COMMENT, TEXT, WHITESPACE]) {
         html_misc(p)?;
     }
@@ -74,69 +82,19 @@ pub fn element(p: &mut Parser) -> Option<Continue> {
     _ok
 }
 
-pub fn element_pattern(p: &mut Parser) -> Option<Continue> {
-    p.expect(L_ANGLE)?;
-    if ((p.at_keyword("area") || p.at_keyword("base") || p.at_keyword("br") || p.at_keyword("col") || p.at_keyword("embed") || p.at_keyword("hr") || p.at_keyword("img") || p.at_keyword("input") || p.at_keyword("link") || p.at_keyword("meta") || p.at_keyword("param") || p.at_keyword("source") || p.at_keyword("track") || p.at_keyword("wbr")) && p.at(TAG_NAME)) && {
-        // try --> empty_element_tag_name (WS)? (attribute (WS)?)* ('>' | '/>')
-        let mut _checkpoint = p.checkpoint(true);
-        catch!({
-            empty_element_tag_name(p)?;
-            p.eat(WS);
-            while p.at(TAG_NAME) {
-                let _checkpoint = p.checkpoint_ambiguous();
-                catch!({
-                    attribute(p)?;
-                    p.eat(WS);
-                    Some(Continue)
-                });
-                if !p.commit(_checkpoint)?.is_ok() {
-                    break;
-                }
-            }
-            p.expect_ts(&tokenset![R_ANGLE, SLASH_R_ANGLE])?;
-            Some(Continue)
-        });
-        p.commit(_checkpoint)?.is_ok()
-    } {
-        // ok
-    } else if p.at(TAG_NAME) {
+pub fn end_tag(p: &mut Parser) -> Option<Continue> {
+    if p.at(L_ANGLE) {
+        p.bump();
+        p.expect(SLASH)?;
+    } else if p.at(L_ANGLE_SLASH) {
         p.bump();
-        p.eat(WS);
-        while p.at(TAG_NAME) {
-            let _checkpoint = p.checkpoint_ambiguous();
-            catch!({
-                attribute(p)?;
-                p.eat(WS);
-                Some(Continue)
-            });
-            if !p.commit(_checkpoint)?.is_ok() {
-                break;
-            }
-        }
-        if p.at(SLASH_R_ANGLE) {
-            p.bump();
-        } else if p.at(R_ANGLE) {
-            p.bump();
-            html_content(p)?;
-            if p.at(L_ANGLE) {
-                p.bump();
-                p.expect(SLASH)?;
-            } else if p.at(L_ANGLE_SLASH) {
-                p.bump();
-            } else {
-                p.expected_ts_in("element_pattern", &tokenset![L_ANGLE, L_ANGLE_SLASH])?;
-            }
-            p.eat(WS);
-            p.expect(TAG_NAME)?;
-            p.eat(WS);
-            p.expect(R_ANGLE)?;
-        } else {
-            p.expected_ts_in("element_pattern", &tokenset![R_ANGLE, SLASH_R_ANGLE])?;
-        }
     } else {
-        // otherwise, emit an error
-        p.expected_in("element_pattern", TAG_NAME)?;
+        p.expected_ts_in("end_tag", &tokenset![L_ANGLE, L_ANGLE_SLASH])?;
     }
+    p.eat(WS);
+    p.expect(TAG_NAME)?;
+    p.eat(WS);
+    p.expect(R_ANGLE)?;
     Some(Continue)
 }
 
@@ -201,36 +159,94 @@ pub fn attribute_value(p: &mut Parser) -> Option<Continue> {
     p.expect_ts(&tokenset![QUOTED, TAG_NAME])
 }
 
-pub fn html_content(p: &mut Parser) -> Option<Continue> {
-    if p.at_ts(&tokenset![COMMENT, L_ANGLE, TEXT, WHITESPACE]) {
-        if p.at_ts(&tokenset![TEXT, WHITESPACE]) {
-            html_chardata(p)?;
-        }
-        while p.at_ts(&tokenset![COMMENT, L_ANGLE]) {
-            let _checkpoint = p.checkpoint_ambiguous();
-            catch!({
-                if p.at(L_ANGLE) {
-                    element(p)?;
-                } else if p.at(COMMENT) {
-                    p.bump();
-                }
-                if p.at_ts(&tokenset![TEXT, WHITESPACE]) {
-                    html_chardata(p)?;
-                }
-                Some(Continue)
-            });
-            if !p.commit(_checkpoint)?.is_ok() {
-                break;
-            }
-        }
+pub fn html_content_item(p: &mut Parser) -> Option<Continue> {
+    if p.at(L_ANGLE) {
+        element(p)?;
+    } else if ((p.nth_at_keyword(1, "area") || p.nth_at_keyword(1, "base") || p.nth_at_keyword(1, "br") || p.nth_at_keyword(1, "col") || p.nth_at_keyword(1, "embed") || p.nth_at_keyword(1, "hr") || p.nth_at_keyword(1, "img") || p.nth_at_keyword(1, "input") || p.nth_at_keyword(1, "link") || p.nth_at_keyword(1, "meta") || p.nth_at_keyword(1, "param") || p.nth_at_keyword(1, "source") || p.nth_at_keyword(1, "track") || p.nth_at_keyword(1, "wbr")) && p.at(L_ANGLE_SLASH)) && {
+        // try --> void_end_tag
+        let mut _checkpoint = p.checkpoint(true);
+        void_end_tag(p);
+        p.commit(_checkpoint)?.is_ok()
+    } {
+        // ok
+    } else if p.nth_at_keyword(1, "p") && p.at(L_ANGLE_SLASH) {
+        stray_end_tag(p)?;
+    } else if p.at(COMMENT) {
+        p.bump();
+    } else if p.at_ts(&tokenset![TEXT, WHITESPACE]) {
+        html_chardata(p)?;
     } else if p.at(SCRIPT_CONTENT) {
-        if p.at(SCRIPT_CONTENT) {
-            script_block(p)?;
-        }
+        script_block(p)?;
     } else if p.at(STYLE_CONTENT) {
-        if p.at(STYLE_CONTENT) {
-            style_block(p)?;
+        style_block(p)?;
+    } else {
+        // otherwise, emit an error
+        p.expected_ts_in("html_content_item", &AT_HTML_CONTENT_ITEM)?;
+    }
+    Some(Continue)
+}
+
+pub fn void_end_tag(p: &mut Parser) -> Option<Continue> {
+    let _marker = p.start();
+    let _ok = catch!({
+        void_end_tag_open(p)?;
+        p.expect(TAG_NAME)?;
+        p.eat(WS);
+        p.expect(R_ANGLE)?;
+        Some(Continue)
+    });
+    p.complete(_marker, VOID_END_TAG);
+    _ok
+}
+
+pub fn stray_end_tag(p: &mut Parser) -> Option<Continue> {
+    let _marker = p.start();
+    let _ok = catch!({
+        if !(p.nth_at_keyword(1, "p")) {
+            p.error("expected to be nth at keyword 1 (with 'p')")?;
         }
+        p.expect(L_ANGLE_SLASH)?;
+        p.expect(TAG_NAME)?;
+        p.eat(WS);
+        p.expect(R_ANGLE)?;
+        Some(Continue)
+    });
+    p.complete(_marker, STRAY_END_TAG);
+    _ok
+}
+
+pub fn void_end_tag_open(p: &mut Parser) -> Option<Continue> {
+    if p.nth_at_keyword(1, "area") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "base") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "br") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "col") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "embed") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "hr") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "img") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "input") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "link") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "meta") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "param") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "source") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "track") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "wbr") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else {
+        // otherwise, emit an error
+        p.expected_in("void_end_tag_open", L_ANGLE_SLASH)?;
     }
     Some(Continue)
 }
@@ -256,3 +272,4 @@ pub fn style_block(p: &mut Parser) -> Option<Continue> {
     p.complete(_marker, STYLE_BLOCK);
     _ok
 }
+pub(crate) const AT_HTML_CONTENT_ITEM: TokenSet = tokenset![COMMENT, L_ANGLE, L_ANGLE_SLASH, SCRIPT_CONTENT, STYLE_CONTENT, TEXT, WHITESPACE];
@@ -0,0 +1,173 @@
+use crate::grammar::*;
+use crate::syntax_kind::*;
+use code_grammar::{catch, tokenset, Parser, TokenSet};
+use code_grammar::parser::Continue;
+
+/// Elements which can't have any content, so they don't have an end tag.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source", "track", "wbr",
+];
+
+/// The start tags which implicitly close an element with an optional end tag.
+enum ClosedBy {
+    Tags(&'static [&'static str]),
+    /// Any start tag except these (e.g. `<head>` is closed by the first tag which isn't metadata)
+    AnyExcept(&'static [&'static str]),
+}
+
+use self::ClosedBy::*;
+
+/// Elements whose end tag can be omitted, and the start tags which implicitly close them.
+///
+/// These elements are also closed by the end tag of any ancestor (e.g. `</ul>` closes an open `<li>`).
+/// See https://html.spec.whatwg.org/multipage/syntax.html#optional-tags for the specification.
+const OPTIONAL_END_TAGS: &[(&str, ClosedBy)] = &[
+    ("html", Tags(&[])),
+    ("head", AnyExcept(&["base", "link", "meta", "noscript", "script", "style", "template", "title"])),
+    ("body", Tags(&[])),
+    ("li", Tags(&["li"])),
+    ("dt", Tags(&["dd", "dt"])),
+    ("dd", Tags(&["dd", "dt"])),
+    ("p", Tags(&[
+        "address", "article", "aside", "blockquote", "details", "dialog", "div", "dl", "fieldset", "figcaption",
+        "figure", "footer", "form", "h1", "h2", "h3", "h4", "h5", "h6", "header", "hgroup", "hr", "main", "menu",
+        "nav", "ol", "p", "pre", "section", "table", "ul",
+    ])),
+    ("rt", Tags(&["rp", "rt"])),
+    ("rp", Tags(&["rp", "rt"])),
+    ("optgroup", Tags(&["hr", "optgroup"])),
+    ("option", Tags(&["hr", "optgroup", "option"])),
+    ("colgroup", AnyExcept(&["col", "template"])),
+    ("caption", Tags(&["caption", "col", "colgroup", "tbody", "td", "tfoot", "th", "thead", "tr"])),
+    ("thead", Tags(&["tbody", "tfoot"])),
+    ("tbody", Tags(&["tbody", "tfoot"])),
+    ("tfoot", Tags(&[])),
+    ("tr", Tags(&["tbody", "tfoot", "thead", "tr"])),
+    ("td", Tags(&["tbody", "td", "tfoot", "th", "thead", "tr"])),
+    ("th", Tags(&["tbody", "td", "tfoot", "th", "thead", "tr"])),
+];
+
+/// A function which parses a grammar rule (e.g. the rules in `grammar.rs`).
+pub type Rule = for<'p, 'a, 'b> fn(&'p mut Parser<'a, 'b>) -> Option<Continue>;
+
+/// The rules which parse the attributes and content of an element, so that grammars
+/// which extend html (e.g. vue templates) can share how end tags are implied.
+pub struct ElementRules {
+    pub attribute: Rule,
+    pub attribute_start: TokenSet<'static>,
+    pub content_item: Rule,
+    pub content_start: TokenSet<'static>,
+}
+
+const HTML_ELEMENT: ElementRules = ElementRules {
+    attribute,
+    attribute_start: tokenset![TAG_NAME],
+    content_item: html_content_item,
+    content_start: AT_HTML_CONTENT_ITEM,
+};
+
+pub fn element_pattern(p: &mut Parser) -> Option<Continue> {
+    element_pattern_with(p, &HTML_ELEMENT)
+}
+
+pub fn element_pattern_with(p: &mut Parser, rules: &ElementRules) -> Option<Continue> {
+    p.expect(L_ANGLE)?;
+    let name = p.nth_text(0);
+    let is_void = VOID_ELEMENTS.iter().any(|tag| p.at_keyword(tag));
+    let optional_end_tag = OPTIONAL_END_TAGS.iter().find(|(tag, _)| p.at_keyword(tag));
+    p.expect(TAG_NAME)?;
+    p.eat(WS);
+    while p.at_ts(&rules.attribute_start) {
+        let _checkpoint = p.checkpoint_ambiguous();
+        catch!({
+            (rules.attribute)(p)?;
+            p.eat(WS);
+            Some(Continue)
+        });
+        if !p.commit(_checkpoint)?.is_ok() {
+            break;
+        }
+    }
+    if is_void {
+        return p.expect_ts(&tokenset![R_ANGLE, SLASH_R_ANGLE]);
+    }
+    if p.eat(SLASH_R_ANGLE) {
+        return Some(Continue);
+    }
+    p.expect(R_ANGLE)?;
+
+    p.open_tag(name);
+    let content = element_content(p, rules, optional_end_tag);
+    p.close_tag();
+    content?;
+    match optional_end_tag {
+        Some((tag, _)) if !at_end_tag_of(p, tag) => {
+            p.complete_empty(IMPLIED_END_TAG);
+            Some(Continue)
+        }
+        _ => end_tag(p),
+    }
+}
+
+/// Parses the content of an element until its end tag, or the start tag which implicitly closes it.
+fn element_content(
+    p: &mut Parser,
+    rules: &ElementRules,
+    optional_end_tag: Option<&(&str, ClosedBy)>,
+) -> Option<Continue> {
+    while p.at_ts(&rules.content_start) && (!at_end_tag(p) || at_stray_end_tag(p)) {
+        if let Some((_, closed_by)) = optional_end_tag {
+            if at_start_tag_in(p, closed_by) {
+                break;
+            }
+        }
+        (rules.content_item)(p)?;
+    }
+    Some(Continue)
+}
+
+/// Finds the position of the tag name, if the parser is at an end tag (e.g. `</li>`).
+fn end_tag_name(p: &Parser) -> Option<usize> {
+    let n = if p.at(L_ANGLE_SLASH) {
+        1
+    } else if p.at(L_ANGLE) && p.nth(1) == SLASH {
+        2
+    } else {
+        return None;
+    };
+    if p.nth(n) == WS {
+        Some(n + 1)
+    } else {
+        Some(n)
+    }
+}
+
+/// Checks if the parser is at an end tag, ignoring the end tags of void elements (e.g. `</br>`).
+fn at_end_tag(p: &Parser) -> bool {
+    match end_tag_name(p) {
+        Some(n) => !VOID_ELEMENTS.iter().any(|tag| p.nth_at_keyword(n, tag)),
+        None => false,
+    }
+}
+
+/// Checks if the parser is at a `</p>` which doesn't close any open `<p>`, so browsers treat it as an empty `<p>`.
+fn at_stray_end_tag(p: &Parser) -> bool {
+    p.at(L_ANGLE_SLASH) && at_end_tag_of(p, "p") && !p.open_tags().iter().any(|tag| p.is_keyword(tag, "p"))
+}
+
+fn at_end_tag_of(p: &Parser, tag: &str) -> bool {
+    match end_tag_name(p) {
+        Some(n) => p.nth_at_keyword(n, tag),
+        None => false,
+    }
+}
+
+fn at_start_tag_in(p: &Parser, closed_by: &ClosedBy) -> bool {
+    if !p.at(L_ANGLE) || p.nth(1) != TAG_NAME {
+        return false;
+    }
+    match closed_by {
+        Tags(tags) => tags.iter().any(|tag| p.nth_at_keyword(1, tag)),
+        AnyExcept(tags) => !tags.iter().any(|tag| p.nth_at_keyword(1, tag)),
+    }
+}
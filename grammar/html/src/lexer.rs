@@ -255,9 +255,11 @@ impl Lexer for HtmlLexer {
                     // In tag mode, we emit many common symbols raw so that
                     // the lexer can be re-used usefully for enriched HTML formats.
                     '@' => AT,
+                    '#' => HASH,
                     '~' => TILDE,
                     '!' => BANG,
                     ':' => COLON,
+                    '.' => DOT,
                     '$' => DOLLAR,
                     '%' => PERCENT,
                     '^' => CARET,
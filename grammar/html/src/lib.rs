@@ -1,5 +1,6 @@
 pub mod ast;
 pub mod grammar;
+pub mod grammar_ext;
 pub mod lexer;
 pub mod scan;
 pub mod syntax_kind;
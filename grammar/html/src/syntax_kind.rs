@@ -46,6 +46,9 @@ syntax_kinds! {
         DOCUMENT_TYPE 101
         ELEMENT 102
         ATTRIBUTE 103
+        VOID_END_TAG 104
+        IMPLIED_END_TAG 107
+        STRAY_END_TAG 108
         STYLE_BLOCK 105
         SCRIPT_BLOCK 106
     }
@@ -24,6 +24,7 @@ impl Program {
             max_rollback_size: 4,
             preserve_comments: false,
             preserve_whitespace: false,
+            keywords_ignore_case: false,
         });
         let (root, remainder) = parser.parse(grammar::program);
         (Program::new(&root), remainder.text)
@@ -53,8 +54,21 @@ impl Expression {
             max_rollback_size: 4,
             preserve_comments: false,
             preserve_whitespace: false,
+            keywords_ignore_case: false,
+        });
+        let (root, remainder) = parser.parse(|p| {
+            if !p.at_ts(&grammar::AT_EXPRESSION) {
+                // N.B. the root must be an expression, so an empty identifier stands in for the missing one
+                let marker = p.start();
+                p.error("expected an expression");
+                p.complete(marker, IDENTIFIER);
+                return None;
+            }
+
+            // N.B. allow the grammar to backtrack from ambiguous syntax, as it can within a statement
+            let _checkpoint = p.checkpoint(true);
+            grammar::expression(p)
         });
-        let (root, remainder) = parser.parse(grammar::expression);
         (Expression::new(&root), remainder.text)
     }
 
@@ -82,6 +96,7 @@ impl Pattern {
             max_rollback_size: 4,
             preserve_comments: false,
             preserve_whitespace: false,
+            keywords_ignore_case: false,
         });
         let (root, remainder) = parser.parse(grammar::pattern);
         (Pattern::new(&root), remainder.text)
@@ -173,6 +188,18 @@ impl BlockStatement {
     }
 }
 
+impl IfStatement {
+    pub fn test(&self) -> Option<&Expression> {
+        self.syntax.children().find_map(Expression::cast)
+    }
+    pub fn consequent(&self) -> Option<&Statement> {
+        self.syntax.children().filter_map(Statement::cast).next()
+    }
+    pub fn alternate(&self) -> Option<&Statement> {
+        self.syntax.children().filter_map(Statement::cast).nth(1)
+    }
+}
+
 impl ExpressionStatement {
     pub fn expression(&self) -> Option<&Expression> {
         self.syntax.first_child().and_then(Expression::cast)
@@ -197,6 +224,69 @@ impl LabeledStatement {
     }
 }
 
+impl ImportDeclaration {
+    pub fn specifiers(&self) -> impl Iterator<Item = &ModuleSpecifier> {
+        self.syntax.children().filter_map(ModuleSpecifier::cast)
+    }
+    pub fn source(&self) -> Option<&Literal> {
+        self.syntax.last_child().and_then(Literal::cast)
+    }
+}
+
+impl ExportNamedDeclaration {
+    pub fn declaration(&self) -> Option<&Declaration> {
+        self.syntax.first_child().and_then(Declaration::cast)
+    }
+    pub fn specifiers(&self) -> impl Iterator<Item = &ExportSpecifier> {
+        self.syntax.children().filter_map(ExportSpecifier::cast)
+    }
+    pub fn source(&self) -> Option<&Literal> {
+        self.syntax.last_child().and_then(Literal::cast)
+    }
+}
+
+impl ExportSpecifier {
+    pub fn local(&self) -> &Identifier {
+        self.syntax.first_child().and_then(Identifier::cast).unwrap()
+    }
+    pub fn exported(&self) -> &Identifier {
+        self.syntax.last_child().and_then(Identifier::cast).unwrap()
+    }
+}
+
+impl ExportDefaultDeclaration {
+    pub fn declaration(&self) -> Option<&Expression> {
+        self.syntax.first_child().and_then(Expression::cast)
+    }
+}
+
+impl ExportAllDeclaration {
+    pub fn source(&self) -> Option<&Literal> {
+        self.syntax.last_child().and_then(Literal::cast)
+    }
+}
+
+impl ImportSpecifier {
+    pub fn imported(&self) -> &Identifier {
+        self.syntax.first_child().and_then(Identifier::cast).unwrap()
+    }
+    pub fn local(&self) -> &Identifier {
+        self.syntax.last_child().and_then(Identifier::cast).unwrap()
+    }
+}
+
+impl ImportDefaultSpecifier {
+    pub fn local(&self) -> &Identifier {
+        self.syntax.first_child().and_then(Identifier::cast).unwrap()
+    }
+}
+
+impl ImportNamespaceSpecifier {
+    pub fn local(&self) -> &Identifier {
+        self.syntax.last_child().and_then(Identifier::cast).unwrap()
+    }
+}
+
 impl ArrayExpression {
     pub fn elements(&self) -> impl Iterator<Item = &Expression> {
         self.syntax.children().filter_map(Expression::cast)
@@ -390,6 +480,10 @@ impl CallExpression {
     pub fn callee(&self) -> Option<&Expression> {
         self.syntax.first_child().and_then(Expression::cast)
     }
+    /// Returns true for a dynamic `import(...)`, whose callee isn't an expression.
+    pub fn is_import(&self) -> bool {
+        self.syntax.first_child().and_then(Import::cast).is_some()
+    }
     pub fn arguments(&self) -> impl Iterator<Item = &Expression> {
         self.syntax.children().skip(1).filter_map(Expression::cast)
         /*
@@ -448,7 +542,13 @@ impl TaggedTemplateExpression {
 
 impl FunctionExpression {
     pub fn id(&self) -> Option<&Identifier> {
-        self.syntax.first_child().and_then(Identifier::cast)
+        // N.B. the name is optional, so stop before the params (e.g. `function (a) {}`)
+        self.syntax.children_with_tokens()
+            .take_while(|syn| syn.kind() != L_PAREN)
+            .find_map(|syn| match syn {
+                SyntaxElement::Node(node) => Identifier::cast(node),
+                SyntaxElement::Token(_) => None,
+            })
     }
     pub fn params(&self) -> impl Iterator<Item = &Pattern> {
         self.syntax.children_with_tokens()
@@ -598,8 +698,8 @@ impl AssignmentProperty {
 
 impl Identifier {
     pub fn name(&self) -> &str {
-        // N.B. Token may be an `IDENTIFIER` but it may also be a `*_KW` token
-        self.syntax.first_token().unwrap().text().as_str()
+        // N.B. Token may be an `IDENTIFIER` but it may also be a `*_KW` token, or missing after a syntax error
+        self.syntax.first_token().map(|token| token.text().as_str()).unwrap_or_default()
     }
 }
 
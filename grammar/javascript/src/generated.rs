@@ -18,6 +18,7 @@ pub mod ast {
         ClassBody = CLASS_BODY,
         Expression,
         Function,
+        Import = IMPORT_EXPRESSION,
         MethodDefinition = METHOD_DEFINITION,
         ModuleDeclaration,
         ModuleSpecifier,
@@ -39,6 +40,7 @@ pub mod ast {
                 NodeKind::ClassBody(node) => node.type_(),
                 NodeKind::Expression(node) => node.type_(),
                 NodeKind::Function(node) => node.type_(),
+                NodeKind::Import(node) => node.type_(),
                 NodeKind::MethodDefinition(node) => node.type_(),
                 NodeKind::ModuleDeclaration(node) => node.type_(),
                 NodeKind::ModuleSpecifier(node) => node.type_(),
@@ -436,6 +438,12 @@ pub mod ast {
             "IfStatement"
         }
     }
+    ast_node!(Import, IMPORT_EXPRESSION);
+    impl Import {
+        pub fn type_(&self) -> &'static str {
+            "Import"
+        }
+    }
     ast_node!(ImportDeclaration, IMPORT_DECLARATION);
     impl ImportDeclaration {
         pub fn type_(&self) -> &'static str {
@@ -698,43 +706,44 @@ pub mod syntax_kind {
             FUNCTION_DECLARATION 232
             FUNCTION_EXPRESSION 233
             IF_STATEMENT 234
-            IMPORT_DECLARATION 235
-            IMPORT_DEFAULT_SPECIFIER 236
-            IMPORT_NAMESPACE_SPECIFIER 237
-            IMPORT_SPECIFIER 238
-            LABELED_STATEMENT 239
-            LITERAL 240
-            LOGICAL_EXPRESSION 241
-            MEMBER_EXPRESSION 242
-            META_PROPERTY 243
-            METHOD_DEFINITION 244
-            NEW_EXPRESSION 245
-            OBJECT_EXPRESSION 246
-            OBJECT_PATTERN 247
-            PROGRAM 248
-            PROPERTY 249
-            REST_ELEMENT 250
-            RETURN_STATEMENT 251
-            SEQUENCE_EXPRESSION 252
-            SPREAD_ELEMENT 253
-            SUPER_EXPRESSION 254
-            SWITCH_CASE 255
-            SWITCH_STATEMENT 256
-            TS_AS_EXPRESSION 257
-            TS_NON_NULL_EXPRESSION 258
-            TAGGED_TEMPLATE_EXPRESSION 259
-            TEMPLATE_ELEMENT 260
-            TEMPLATE_EXPRESSION 261
-            THIS_EXPRESSION 262
-            THROW_STATEMENT 263
-            TRY_STATEMENT 264
-            UNARY_EXPRESSION 265
-            UPDATE_EXPRESSION 266
-            VARIABLE_DECLARATION 267
-            VARIABLE_DECLARATOR 268
-            WHILE_STATEMENT 269
-            WITH_STATEMENT 270
-            YIELD_EXPRESSION 271
+            IMPORT_EXPRESSION 235
+            IMPORT_DECLARATION 236
+            IMPORT_DEFAULT_SPECIFIER 237
+            IMPORT_NAMESPACE_SPECIFIER 238
+            IMPORT_SPECIFIER 239
+            LABELED_STATEMENT 240
+            LITERAL 241
+            LOGICAL_EXPRESSION 242
+            MEMBER_EXPRESSION 243
+            META_PROPERTY 244
+            METHOD_DEFINITION 245
+            NEW_EXPRESSION 246
+            OBJECT_EXPRESSION 247
+            OBJECT_PATTERN 248
+            PROGRAM 249
+            PROPERTY 250
+            REST_ELEMENT 251
+            RETURN_STATEMENT 252
+            SEQUENCE_EXPRESSION 253
+            SPREAD_ELEMENT 254
+            SUPER_EXPRESSION 255
+            SWITCH_CASE 256
+            SWITCH_STATEMENT 257
+            TS_AS_EXPRESSION 258
+            TS_NON_NULL_EXPRESSION 259
+            TAGGED_TEMPLATE_EXPRESSION 260
+            TEMPLATE_ELEMENT 261
+            TEMPLATE_EXPRESSION 262
+            THIS_EXPRESSION 263
+            THROW_STATEMENT 264
+            TRY_STATEMENT 265
+            UNARY_EXPRESSION 266
+            UPDATE_EXPRESSION 267
+            VARIABLE_DECLARATION 268
+            VARIABLE_DECLARATOR 269
+            WHILE_STATEMENT 270
+            WITH_STATEMENT 271
+            YIELD_EXPRESSION 272
         }
     }
 }
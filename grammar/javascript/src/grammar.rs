@@ -18,7 +18,7 @@ pub fn program(p: &mut Parser) -> Option<Continue> {
     let _marker = p.start();
     let _ok = catch!({
         p.eat(SHEBANG);
-        if p.at_ts(&_TS0) || (!p.at(L_CURLY) && !p.at(FUNCTION_KW) && p.at_ts(&_TS1)) {
+        if p.at_ts(&_TS0) || ((p.at(IMPORT_KW) && p.nth_at(1, L_PAREN) || !p.at(L_CURLY) && !p.at(FUNCTION_KW) && !p.at(IMPORT_KW)) && p.at_ts(&_TS1)) {
             source_elements(p)?;
         }
         p.expect(EOF)?;
@@ -29,11 +29,17 @@ pub fn program(p: &mut Parser) -> Option<Continue> {
 }
 
 pub fn source_element(p: &mut Parser) -> Option<Continue> {
-    if p.at_ts(&_TS2) || (!p.at(L_CURLY) && !p.at(FUNCTION_KW) && p.at_ts(&_TS1)) {
-        statement(p)?;
+    if (p.at_ts(&_TS2) || ((p.at(IMPORT_KW) && p.nth_at(1, L_PAREN) || !p.at(L_CURLY) && !p.at(FUNCTION_KW) && !p.at(IMPORT_KW)) && p.at_ts(&_TS3))) && {
+        // try --> statement
+        let mut _checkpoint = p.checkpoint(true);
+        statement(p);
+        p.commit(_checkpoint)?.is_ok()
+    } {
+        // ok
     } else if p.at_ts(&tokenset![EXPORT_KW, IMPORT_KW]) {
         module_declaration(p)?;
     } else {
+        // otherwise, emit an error
         p.expected_ts_in("source_element", &AT_SOURCE_ELEMENT)?;
     }
     Some(Continue)
@@ -93,7 +99,7 @@ pub fn export_declaration(p: &mut Parser) -> Option<Continue> {
         end_of_statement(p)?;
         p.complete(_checkpoint.branch(&_marker), EXPORT_ALL_DECLARATION);
     } else {
-        p.expected_ts_in("export_declaration", &_TS3)?;
+        p.expected_ts_in("export_declaration", &_TS4)?;
     }
     Some(Continue)
 }
@@ -276,7 +282,7 @@ pub fn statement(p: &mut Parser) -> Option<Continue> {
         p.commit(_checkpoint)?.is_ok()
     } {
         // ok
-    } else if ((p.at_keyword("async") && !p.at(L_CURLY) && !p.at(FUNCTION_KW) && p.at(IDENTIFIER)) || (!p.at(L_CURLY) && !p.at(FUNCTION_KW) && p.at_ts(&AT_EXPRESSION))) && {
+    } else if (((p.at(IMPORT_KW) && p.nth_at(1, L_PAREN) || !p.at(L_CURLY) && !p.at(FUNCTION_KW) && !p.at(IMPORT_KW)) && p.at_ts(&AT_EXPRESSION)) || ((p.at_keyword("async") && p.at(IMPORT_KW) && p.nth_at(1, L_PAREN) || p.at_keyword("async") && !p.at(L_CURLY) && !p.at(FUNCTION_KW) && !p.at(IMPORT_KW)) && p.at(IDENTIFIER))) && {
         // try --> expression_statement
         let mut _checkpoint = p.checkpoint(true);
         expression_statement(p);
@@ -296,7 +302,7 @@ pub fn block(p: &mut Parser) -> Option<Continue> {
     let _marker = p.start();
     let _ok = catch!({
         p.expect(L_CURLY)?;
-        if p.at_ts(&_TS2) || (!p.at(L_CURLY) && !p.at(FUNCTION_KW) && p.at_ts(&_TS1)) {
+        if p.at_ts(&_TS2) || ((p.at(IMPORT_KW) && p.nth_at(1, L_PAREN) || !p.at(L_CURLY) && !p.at(FUNCTION_KW) && !p.at(IMPORT_KW)) && p.at_ts(&_TS3)) {
             statement_list(p)?;
         }
         p.expect(R_CURLY)?;
@@ -308,7 +314,7 @@ pub fn block(p: &mut Parser) -> Option<Continue> {
 
 pub fn statement_list(p: &mut Parser) -> Option<Continue> {
     statement(p)?;
-    while p.at_ts(&_TS2) || (!p.at(L_CURLY) && !p.at(FUNCTION_KW) && p.at_ts(&_TS1)) {
+    while p.at_ts(&_TS2) || ((p.at(IMPORT_KW) && p.nth_at(1, L_PAREN) || !p.at(L_CURLY) && !p.at(FUNCTION_KW) && !p.at(IMPORT_KW)) && p.at_ts(&_TS3)) {
         let _checkpoint = p.checkpoint_ambiguous();
         statement(p);
         if !p.commit(_checkpoint)?.is_ok() {
@@ -364,17 +370,47 @@ pub fn empty_statement(p: &mut Parser) -> Option<Continue> {
 }
 
 pub fn expression_statement(p: &mut Parser) -> Option<Continue> {
-    let _marker = p.start();
-    let _ok = catch!({
-        if !(!p.at(L_CURLY) && !p.at(FUNCTION_KW)) {
-            p.error("expected to be not at L_CURLY and not at FUNCTION_KW")?;
+    if ((p.at_keyword("async") && !p.at(L_CURLY) && !p.at(FUNCTION_KW) && !p.at(IMPORT_KW) && p.at(IDENTIFIER)) || (!p.at(L_CURLY) && !p.at(FUNCTION_KW) && !p.at(IMPORT_KW) && p.at_ts(&AT_EXPRESSION))) && {
+        // try --> { <predicate> }? expression_list end_of_statement #EXPRESSION_STATEMENT
+        let mut _checkpoint = p.checkpoint(true);
+        catch!({
+            let _marker = p.start();
+            let _ok = catch!({
+                if !(!p.at(L_CURLY) && !p.at(FUNCTION_KW) && !p.at(IMPORT_KW)) {
+                    p.error("expected to be not at L_CURLY and not at FUNCTION_KW and not at IMPORT_KW")?;
+                }
+                expression_list(p)?;
+                end_of_statement(p)?;
+                Some(Continue)
+            });
+            p.complete(_marker, EXPRESSION_STATEMENT);
+            if _ok.is_none() {
+                return None;
+            }
+            Some(Continue)
+        });
+        p.commit(_checkpoint)?.is_ok()
+    } {
+        // ok
+    } else if (p.at(IMPORT_KW) && p.nth_at(1, L_PAREN) && p.at_ts(&AT_EXPRESSION)) || (p.at_keyword("async") && p.at(IMPORT_KW) && p.nth_at(1, L_PAREN) && p.at(IDENTIFIER)) {
+        let _marker = p.start();
+        let _ok = catch!({
+            if !(p.at(IMPORT_KW) && p.nth_at(1, L_PAREN)) {
+                p.error("expected to be at IMPORT_KW and nth at 1 (with L_PAREN)")?;
+            }
+            expression_list(p)?;
+            end_of_statement(p)?;
+            Some(Continue)
+        });
+        p.complete(_marker, EXPRESSION_STATEMENT);
+        if _ok.is_none() {
+            return None;
         }
-        expression_list(p)?;
-        end_of_statement(p)?;
-        Some(Continue)
-    });
-    p.complete(_marker, EXPRESSION_STATEMENT);
-    _ok
+    } else {
+        // otherwise, emit an error
+        p.expected_ts_in("expression_statement", &AT_EXPRESSION)?;
+    }
+    Some(Continue)
 }
 
 pub fn if_statement(p: &mut Parser) -> Option<Continue> {
@@ -400,7 +436,7 @@ pub fn for_statement(p: &mut Parser) -> Option<Continue> {
     let _marker = p.start();
     p.expect(FOR_KW)?;
     p.expect(L_PAREN)?;
-    if p.at_ts(&_TS4) && {
+    if p.at_ts(&_TS5) && {
         // try --> (expression_list)? ';' (expression_list)? ';' (expression_list)? ')' statement_list #FOR_STATEMENT
         let mut _checkpoint = p.checkpoint(true);
         catch!({
@@ -490,7 +526,7 @@ pub fn for_statement(p: &mut Parser) -> Option<Continue> {
         p.complete(_checkpoint.branch(&_marker), FOR_OF_STATEMENT);
     } else {
         // otherwise, emit an error
-        p.expected_ts_in("for_statement", &_TS5)?;
+        p.expected_ts_in("for_statement", &_TS6)?;
     }
     Some(Continue)
 }
@@ -631,7 +667,7 @@ pub fn case_clause(p: &mut Parser) -> Option<Continue> {
         p.expect(CASE_KW)?;
         expression_list(p)?;
         p.expect(COLON)?;
-        if p.at_ts(&_TS2) || (!p.at(L_CURLY) && !p.at(FUNCTION_KW) && p.at_ts(&_TS1)) {
+        if p.at_ts(&_TS2) || ((p.at(IMPORT_KW) && p.nth_at(1, L_PAREN) || !p.at(L_CURLY) && !p.at(FUNCTION_KW) && !p.at(IMPORT_KW)) && p.at_ts(&_TS3)) {
             statement_list(p)?;
         }
         Some(Continue)
@@ -645,7 +681,7 @@ pub fn default_clause(p: &mut Parser) -> Option<Continue> {
     let _ok = catch!({
         p.expect(DEFAULT_KW)?;
         p.expect(COLON)?;
-        if p.at_ts(&_TS2) || (!p.at(L_CURLY) && !p.at(FUNCTION_KW) && p.at_ts(&_TS1)) {
+        if p.at_ts(&_TS2) || ((p.at(IMPORT_KW) && p.nth_at(1, L_PAREN) || !p.at(L_CURLY) && !p.at(FUNCTION_KW) && !p.at(IMPORT_KW)) && p.at_ts(&_TS3)) {
             statement_list(p)?;
         }
         Some(Continue)
@@ -970,7 +1006,7 @@ pub fn function_body(p: &mut Parser) -> Option<Continue> {
     let _marker = p.start();
     let _ok = catch!({
         p.expect(L_CURLY)?;
-        if p.at_ts(&_TS0) || (!p.at(L_CURLY) && !p.at(FUNCTION_KW) && p.at_ts(&_TS1)) {
+        if p.at_ts(&_TS0) || ((p.at(IMPORT_KW) && p.nth_at(1, L_PAREN) || !p.at(L_CURLY) && !p.at(FUNCTION_KW) && !p.at(IMPORT_KW)) && p.at_ts(&_TS1)) {
             source_elements(p)?;
         }
         p.expect(R_CURLY)?;
@@ -982,7 +1018,7 @@ pub fn function_body(p: &mut Parser) -> Option<Continue> {
 
 pub fn source_elements(p: &mut Parser) -> Option<Continue> {
     source_element(p)?;
-    while p.at_ts(&_TS0) || (!p.at(L_CURLY) && !p.at(FUNCTION_KW) && p.at_ts(&_TS1)) {
+    while p.at_ts(&_TS0) || ((p.at(IMPORT_KW) && p.nth_at(1, L_PAREN) || !p.at(L_CURLY) && !p.at(FUNCTION_KW) && !p.at(IMPORT_KW)) && p.at_ts(&_TS1)) {
         let _checkpoint = p.checkpoint_ambiguous();
         source_element(p);
         if !p.commit(_checkpoint)?.is_ok() {
@@ -1581,8 +1617,8 @@ pub fn end_of_statement(p: &mut Parser) -> Option<Continue> {
 pub(crate) const AT_ASSIGNMENT_OPERATOR: TokenSet = tokenset![AMPERSAND_EQ, ASTERISK_EQ, CARET_EQ, EQ, MINUS_EQ, PERCENT_EQ, PIPE_EQ, PLUS_EQ, SHL_EQ, SHR_EQ, SHU_EQ, SLASH_EQ];
 pub(crate) const AT_ASSIGNMENT_PROPERTY: TokenSet = tokenset![BOOLEAN_KW, BREAK_KW, BYTE_KW, CASE_KW, CATCH_KW, CHAR_KW, CLASS_KW, CONST_KW, CONTINUE_KW, DEBUGGER_KW, DEFAULT_KW, DELETE_KW, DOUBLE_KW, DO_KW, ELSE_KW, ENUM_KW, EXPORT_KW, EXTENDS_KW, FALSE_KW, FINALLY_KW, FLOAT_KW, FOR_KW, FUNCTION_KW, IDENTIFIER, IF_KW, IMPLEMENTS_KW, IMPORT_KW, INSTANCEOF_KW, INTERFACE_KW, INT_KW, IN_KW, LET_KW, LONG_KW, L_SQUARE, NEW_KW, NULL_KW, PACKAGE_KW, PRIVATE_KW, PROTECTED_KW, PUBLIC_KW, RETURN_KW, STATIC_KW, SUPER_KW, SWITCH_KW, THIS_KW, THROW_KW, TRUE_KW, TRY_KW, TYPEOF_KW, VAR_KW, VOID_KW, WHILE_KW, WITH_KW, YIELD_KW];
 pub(crate) const AT_CLASS_ELEMENT: TokenSet = tokenset![ASTERISK, BOOLEAN_KW, BREAK_KW, BYTE_KW, CASE_KW, CATCH_KW, CHAR_KW, CLASS_KW, CONST_KW, CONTINUE_KW, DEBUGGER_KW, DEFAULT_KW, DELETE_KW, DOUBLE_KW, DO_KW, ELSE_KW, ENUM_KW, EXPORT_KW, EXTENDS_KW, FALSE_KW, FINALLY_KW, FLOAT_KW, FOR_KW, FUNCTION_KW, IDENTIFIER, IF_KW, IMPLEMENTS_KW, IMPORT_KW, INSTANCEOF_KW, INTERFACE_KW, INT_KW, IN_KW, LET_KW, LONG_KW, NEW_KW, NULL_KW, NUMBER_LITERAL, PACKAGE_KW, PRIVATE_KW, PROTECTED_KW, PUBLIC_KW, RETURN_KW, SEMICOLON, STATIC_KW, STRING_LITERAL, SUPER_KW, SWITCH_KW, THIS_KW, THROW_KW, TRUE_KW, TRY_KW, TYPEOF_KW, VAR_KW, VOID_KW, WHILE_KW, WITH_KW, YIELD_KW];
-pub(crate) const AT_ELEMENT_OR_SPREAD: TokenSet = tokenset![AWAIT_KW, BANG, CLASS_KW, DECREMENT, DELETE_KW, DOTDOTDOT, FALSE_KW, FUNCTION_KW, IDENTIFIER, INCREMENT, L_CURLY, L_PAREN, L_SQUARE, MINUS, NEW_KW, NULL_KW, NUMBER_LITERAL, PLUS, REGEXP_LITERAL, STRING_LITERAL, SUPER_KW, TEMPLATE_LITERAL, THIS_KW, TILDE, TRUE_KW, TYPEOF_KW, VOID_KW, YIELD_KW];
-pub(crate) const AT_EXPRESSION: TokenSet = tokenset![AWAIT_KW, BANG, CLASS_KW, DECREMENT, DELETE_KW, FALSE_KW, FUNCTION_KW, IDENTIFIER, INCREMENT, L_CURLY, L_PAREN, L_SQUARE, MINUS, NEW_KW, NULL_KW, NUMBER_LITERAL, PLUS, REGEXP_LITERAL, STRING_LITERAL, SUPER_KW, TEMPLATE_LITERAL, THIS_KW, TILDE, TRUE_KW, TYPEOF_KW, VOID_KW, YIELD_KW];
+pub(crate) const AT_ELEMENT_OR_SPREAD: TokenSet = tokenset![AWAIT_KW, BANG, CLASS_KW, DECREMENT, DELETE_KW, DOTDOTDOT, FALSE_KW, FUNCTION_KW, IDENTIFIER, IMPORT_KW, INCREMENT, L_CURLY, L_PAREN, L_SQUARE, MINUS, NEW_KW, NULL_KW, NUMBER_LITERAL, PLUS, REGEXP_LITERAL, STRING_LITERAL, SUPER_KW, TEMPLATE_LITERAL, THIS_KW, TILDE, TRUE_KW, TYPEOF_KW, VOID_KW, YIELD_KW];
+pub(crate) const AT_EXPRESSION: TokenSet = tokenset![AWAIT_KW, BANG, CLASS_KW, DECREMENT, DELETE_KW, FALSE_KW, FUNCTION_KW, IDENTIFIER, IMPORT_KW, INCREMENT, L_CURLY, L_PAREN, L_SQUARE, MINUS, NEW_KW, NULL_KW, NUMBER_LITERAL, PLUS, REGEXP_LITERAL, STRING_LITERAL, SUPER_KW, TEMPLATE_LITERAL, THIS_KW, TILDE, TRUE_KW, TYPEOF_KW, VOID_KW, YIELD_KW];
 pub(crate) const AT_GENERATOR_METHOD: TokenSet = tokenset![ASTERISK, BOOLEAN_KW, BREAK_KW, BYTE_KW, CASE_KW, CATCH_KW, CHAR_KW, CLASS_KW, CONST_KW, CONTINUE_KW, DEBUGGER_KW, DEFAULT_KW, DELETE_KW, DOUBLE_KW, DO_KW, ELSE_KW, ENUM_KW, EXPORT_KW, EXTENDS_KW, FALSE_KW, FINALLY_KW, FLOAT_KW, FOR_KW, FUNCTION_KW, IDENTIFIER, IF_KW, IMPLEMENTS_KW, IMPORT_KW, INSTANCEOF_KW, INTERFACE_KW, INT_KW, IN_KW, LET_KW, LONG_KW, NEW_KW, NULL_KW, PACKAGE_KW, PRIVATE_KW, PROTECTED_KW, PUBLIC_KW, RETURN_KW, STATIC_KW, SUPER_KW, SWITCH_KW, THIS_KW, THROW_KW, TRUE_KW, TRY_KW, TYPEOF_KW, VAR_KW, VOID_KW, WHILE_KW, WITH_KW, YIELD_KW];
 pub(crate) const AT_IDENTIFIER_OR_KEYWORD: TokenSet = tokenset![BOOLEAN_KW, BREAK_KW, BYTE_KW, CASE_KW, CATCH_KW, CHAR_KW, CLASS_KW, CONST_KW, CONTINUE_KW, DEBUGGER_KW, DEFAULT_KW, DELETE_KW, DOUBLE_KW, DO_KW, ELSE_KW, ENUM_KW, EXPORT_KW, EXTENDS_KW, FALSE_KW, FINALLY_KW, FLOAT_KW, FOR_KW, FUNCTION_KW, IDENTIFIER, IF_KW, IMPLEMENTS_KW, IMPORT_KW, INSTANCEOF_KW, INTERFACE_KW, INT_KW, IN_KW, LET_KW, LONG_KW, NEW_KW, NULL_KW, PACKAGE_KW, PRIVATE_KW, PROTECTED_KW, PUBLIC_KW, RETURN_KW, STATIC_KW, SUPER_KW, SWITCH_KW, THIS_KW, THROW_KW, TRUE_KW, TRY_KW, TYPEOF_KW, VAR_KW, VOID_KW, WHILE_KW, WITH_KW, YIELD_KW];
 pub(crate) const AT_KEYWORD: TokenSet = tokenset![BOOLEAN_KW, BREAK_KW, BYTE_KW, CASE_KW, CATCH_KW, CHAR_KW, CLASS_KW, CONST_KW, CONTINUE_KW, DEBUGGER_KW, DEFAULT_KW, DELETE_KW, DOUBLE_KW, DO_KW, ELSE_KW, ENUM_KW, EXPORT_KW, EXTENDS_KW, FINALLY_KW, FLOAT_KW, FOR_KW, FUNCTION_KW, IF_KW, IMPLEMENTS_KW, IMPORT_KW, INSTANCEOF_KW, INTERFACE_KW, INT_KW, IN_KW, LET_KW, LONG_KW, NEW_KW, PACKAGE_KW, PRIVATE_KW, PROTECTED_KW, PUBLIC_KW, RETURN_KW, STATIC_KW, SUPER_KW, SWITCH_KW, THIS_KW, THROW_KW, TRY_KW, TYPEOF_KW, VAR_KW, VOID_KW, WHILE_KW, WITH_KW, YIELD_KW];
@@ -1593,10 +1629,11 @@ pub(crate) const AT_PROPERTY_NAME: TokenSet = tokenset![BOOLEAN_KW, BREAK_KW, BY
 pub(crate) const AT_PROPERTY_OR_SPREAD: TokenSet = tokenset![ASTERISK, BOOLEAN_KW, BREAK_KW, BYTE_KW, CASE_KW, CATCH_KW, CHAR_KW, CLASS_KW, CONST_KW, CONTINUE_KW, DEBUGGER_KW, DEFAULT_KW, DELETE_KW, DOTDOTDOT, DOUBLE_KW, DO_KW, ELSE_KW, ENUM_KW, EXPORT_KW, EXTENDS_KW, FALSE_KW, FINALLY_KW, FLOAT_KW, FOR_KW, FUNCTION_KW, IDENTIFIER, IF_KW, IMPLEMENTS_KW, IMPORT_KW, INSTANCEOF_KW, INTERFACE_KW, INT_KW, IN_KW, LET_KW, LONG_KW, L_SQUARE, NEW_KW, NULL_KW, NUMBER_LITERAL, PACKAGE_KW, PRIVATE_KW, PROTECTED_KW, PUBLIC_KW, RETURN_KW, STATIC_KW, STRING_LITERAL, SUPER_KW, SWITCH_KW, THIS_KW, THROW_KW, TRUE_KW, TRY_KW, TYPEOF_KW, VAR_KW, VOID_KW, WHILE_KW, WITH_KW, YIELD_KW];
 pub(crate) const AT_RESERVED_WORD: TokenSet = tokenset![BOOLEAN_KW, BREAK_KW, BYTE_KW, CASE_KW, CATCH_KW, CHAR_KW, CLASS_KW, CONST_KW, CONTINUE_KW, DEBUGGER_KW, DEFAULT_KW, DELETE_KW, DOUBLE_KW, DO_KW, ELSE_KW, ENUM_KW, EXPORT_KW, EXTENDS_KW, FALSE_KW, FINALLY_KW, FLOAT_KW, FOR_KW, FUNCTION_KW, IF_KW, IMPLEMENTS_KW, IMPORT_KW, INSTANCEOF_KW, INTERFACE_KW, INT_KW, IN_KW, LET_KW, LONG_KW, NEW_KW, NULL_KW, PACKAGE_KW, PRIVATE_KW, PROTECTED_KW, PUBLIC_KW, RETURN_KW, STATIC_KW, SUPER_KW, SWITCH_KW, THIS_KW, THROW_KW, TRUE_KW, TRY_KW, TYPEOF_KW, VAR_KW, VOID_KW, WHILE_KW, WITH_KW, YIELD_KW];
 pub(crate) const AT_SOURCE_ELEMENT: TokenSet = tokenset![AWAIT_KW, BANG, BREAK_KW, CLASS_KW, CONST_KW, CONTINUE_KW, DEBUGGER_KW, DECREMENT, DELETE_KW, DO_KW, EXPORT_KW, FALSE_KW, FOR_KW, FUNCTION_KW, IDENTIFIER, IF_KW, IMPORT_KW, INCREMENT, LET_KW, L_CURLY, L_PAREN, L_SQUARE, MINUS, NEW_KW, NULL_KW, NUMBER_LITERAL, PLUS, REGEXP_LITERAL, RETURN_KW, SEMICOLON, STRING_LITERAL, SUPER_KW, SWITCH_KW, TEMPLATE_LITERAL, THIS_KW, THROW_KW, TILDE, TRUE_KW, TRY_KW, TYPEOF_KW, VAR_KW, VOID_KW, WHILE_KW, WITH_KW, YIELD_KW];
-pub(crate) const AT_STATEMENT: TokenSet = tokenset![AWAIT_KW, BANG, BREAK_KW, CLASS_KW, CONST_KW, CONTINUE_KW, DEBUGGER_KW, DECREMENT, DELETE_KW, DO_KW, FALSE_KW, FOR_KW, FUNCTION_KW, IDENTIFIER, IF_KW, INCREMENT, LET_KW, L_CURLY, L_PAREN, L_SQUARE, MINUS, NEW_KW, NULL_KW, NUMBER_LITERAL, PLUS, REGEXP_LITERAL, RETURN_KW, SEMICOLON, STRING_LITERAL, SUPER_KW, SWITCH_KW, TEMPLATE_LITERAL, THIS_KW, THROW_KW, TILDE, TRUE_KW, TRY_KW, TYPEOF_KW, VAR_KW, VOID_KW, WHILE_KW, WITH_KW, YIELD_KW];
+pub(crate) const AT_STATEMENT: TokenSet = tokenset![AWAIT_KW, BANG, BREAK_KW, CLASS_KW, CONST_KW, CONTINUE_KW, DEBUGGER_KW, DECREMENT, DELETE_KW, DO_KW, FALSE_KW, FOR_KW, FUNCTION_KW, IDENTIFIER, IF_KW, IMPORT_KW, INCREMENT, LET_KW, L_CURLY, L_PAREN, L_SQUARE, MINUS, NEW_KW, NULL_KW, NUMBER_LITERAL, PLUS, REGEXP_LITERAL, RETURN_KW, SEMICOLON, STRING_LITERAL, SUPER_KW, SWITCH_KW, TEMPLATE_LITERAL, THIS_KW, THROW_KW, TILDE, TRUE_KW, TRY_KW, TYPEOF_KW, VAR_KW, VOID_KW, WHILE_KW, WITH_KW, YIELD_KW];
 pub(crate) const _TS0: TokenSet = tokenset![BREAK_KW, CLASS_KW, CONST_KW, CONTINUE_KW, DEBUGGER_KW, DO_KW, EXPORT_KW, FOR_KW, FUNCTION_KW, IDENTIFIER, IF_KW, IMPORT_KW, LET_KW, L_CURLY, RETURN_KW, SEMICOLON, SWITCH_KW, THROW_KW, TRY_KW, VAR_KW, WHILE_KW, WITH_KW];
 pub(crate) const _TS1: TokenSet = tokenset![AWAIT_KW, BANG, DECREMENT, DELETE_KW, FALSE_KW, INCREMENT, L_PAREN, L_SQUARE, MINUS, NEW_KW, NULL_KW, NUMBER_LITERAL, PLUS, REGEXP_LITERAL, STRING_LITERAL, SUPER_KW, TEMPLATE_LITERAL, THIS_KW, TILDE, TRUE_KW, TYPEOF_KW, VOID_KW, YIELD_KW];
 pub(crate) const _TS2: TokenSet = tokenset![BREAK_KW, CLASS_KW, CONST_KW, CONTINUE_KW, DEBUGGER_KW, DO_KW, FOR_KW, FUNCTION_KW, IDENTIFIER, IF_KW, LET_KW, L_CURLY, RETURN_KW, SEMICOLON, SWITCH_KW, THROW_KW, TRY_KW, VAR_KW, WHILE_KW, WITH_KW];
-pub(crate) const _TS3: TokenSet = tokenset![ASTERISK, CLASS_KW, CONST_KW, DEFAULT_KW, FUNCTION_KW, IDENTIFIER, LET_KW, L_CURLY, VAR_KW];
-pub(crate) const _TS4: TokenSet = tokenset![AWAIT_KW, BANG, CLASS_KW, DECREMENT, DELETE_KW, FALSE_KW, FUNCTION_KW, IDENTIFIER, INCREMENT, L_CURLY, L_PAREN, L_SQUARE, MINUS, NEW_KW, NULL_KW, NUMBER_LITERAL, PLUS, REGEXP_LITERAL, SEMICOLON, STRING_LITERAL, SUPER_KW, TEMPLATE_LITERAL, THIS_KW, TILDE, TRUE_KW, TYPEOF_KW, VOID_KW, YIELD_KW];
-pub(crate) const _TS5: TokenSet = tokenset![AWAIT_KW, BANG, CLASS_KW, CONST_KW, DECREMENT, DELETE_KW, FALSE_KW, FUNCTION_KW, IDENTIFIER, INCREMENT, LET_KW, L_CURLY, L_PAREN, L_SQUARE, MINUS, NEW_KW, NULL_KW, NUMBER_LITERAL, PLUS, REGEXP_LITERAL, SEMICOLON, STRING_LITERAL, SUPER_KW, TEMPLATE_LITERAL, THIS_KW, TILDE, TRUE_KW, TYPEOF_KW, VAR_KW, VOID_KW, YIELD_KW];
+pub(crate) const _TS3: TokenSet = tokenset![AWAIT_KW, BANG, DECREMENT, DELETE_KW, FALSE_KW, IMPORT_KW, INCREMENT, L_PAREN, L_SQUARE, MINUS, NEW_KW, NULL_KW, NUMBER_LITERAL, PLUS, REGEXP_LITERAL, STRING_LITERAL, SUPER_KW, TEMPLATE_LITERAL, THIS_KW, TILDE, TRUE_KW, TYPEOF_KW, VOID_KW, YIELD_KW];
+pub(crate) const _TS4: TokenSet = tokenset![ASTERISK, CLASS_KW, CONST_KW, DEFAULT_KW, FUNCTION_KW, IDENTIFIER, LET_KW, L_CURLY, VAR_KW];
+pub(crate) const _TS5: TokenSet = tokenset![AWAIT_KW, BANG, CLASS_KW, DECREMENT, DELETE_KW, FALSE_KW, FUNCTION_KW, IDENTIFIER, IMPORT_KW, INCREMENT, L_CURLY, L_PAREN, L_SQUARE, MINUS, NEW_KW, NULL_KW, NUMBER_LITERAL, PLUS, REGEXP_LITERAL, SEMICOLON, STRING_LITERAL, SUPER_KW, TEMPLATE_LITERAL, THIS_KW, TILDE, TRUE_KW, TYPEOF_KW, VOID_KW, YIELD_KW];
+pub(crate) const _TS6: TokenSet = tokenset![AWAIT_KW, BANG, CLASS_KW, CONST_KW, DECREMENT, DELETE_KW, FALSE_KW, FUNCTION_KW, IDENTIFIER, IMPORT_KW, INCREMENT, LET_KW, L_CURLY, L_PAREN, L_SQUARE, MINUS, NEW_KW, NULL_KW, NUMBER_LITERAL, PLUS, REGEXP_LITERAL, SEMICOLON, STRING_LITERAL, SUPER_KW, TEMPLATE_LITERAL, THIS_KW, TILDE, TRUE_KW, TYPEOF_KW, VAR_KW, VOID_KW, YIELD_KW];
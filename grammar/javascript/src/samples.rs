@@ -81,7 +81,7 @@ fn test_parse_sample1() {
     assert_diff!(
       syntax.trim(),
       r#"
-PROGRAM@[0; 621)
+PROGRAM@[0; 622)
   WHITESPACE@[0; 1)
   VARIABLE_DECLARATION@[1; 66)
     VAR_KW@[1; 4)  "var"
@@ -413,6 +413,7 @@ PROGRAM@[0; 621)
           SEMICOLON@[618; 619)  ";"
       WHITESPACE@[619; 620)
       R_CURLY@[620; 621)  "}"
+  WHITESPACE@[621; 622)
 "#.trim()
     );
 }
@@ -426,7 +427,7 @@ fn test_parse_sample2() {
     assert_diff!(
       syntax.trim(),
       r#"
-PROGRAM@[0; 942)
+PROGRAM@[0; 943)
   WHITESPACE@[0; 1)
   IMPORT_DECLARATION@[1; 23)
     IMPORT_KW@[1; 7)  "import"
@@ -830,6 +831,16 @@ PROGRAM@[0; 942)
         R_CURLY@[939; 940)  "}"
       R_PAREN@[940; 941)  ")"
     SEMICOLON@[941; 942)  ";"
+  WHITESPACE@[942; 943)
 "#.trim()
     );
 }
+
+#[test]
+fn test_parse_import_expression_statement() {
+    let text = "import './polyfill';\nimport('./polyfill');\nimport('./a').then(f);\nimport Vue from 'vue';\n";
+    let (root, tail) = Program::parse(text);
+    assert!(root.errors().is_empty() && tail.is_empty(), "{:?}", root.errors());
+    let kinds = root.syntax.children().map(|node| as_debug_repr(node.kind()).unwrap().name).collect::<Vec<_>>();
+    assert_eq!(kinds, vec!["IMPORT_DECLARATION", "EXPRESSION_STATEMENT", "EXPRESSION_STATEMENT", "IMPORT_DECLARATION"]);
+}
@@ -27,6 +27,7 @@ impl Program {
             max_rollback_size: 4,
             preserve_comments: false,
             preserve_whitespace: false,
+            keywords_ignore_case: false,
         });
         let (root, remainder) = parser.parse(grammar::program);
         (Program::new(&root), remainder.text)
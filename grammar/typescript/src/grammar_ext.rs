@@ -206,6 +206,14 @@ pub fn expression(p: &mut Parser) -> Option<Continue> {
             let marker = p.start();
             p.bump();
             p.complete(marker, SUPER_EXPRESSION);
+        } else if p.at(IMPORT_KW) {
+            // N.B. `import` is only an expression as the callee of a dynamic `import(...)`
+            let marker = p.start();
+            p.bump();
+            p.complete(marker, IMPORT_EXPRESSION);
+            if !p.at(L_PAREN) {
+                p.error("expected \"(\" after \"import\"")?;
+            }
         } else if p.at(AWAIT_KW) {
             let marker = p.start();
             p.bump();
@@ -1,6 +1,7 @@
 use crate::grammar;
 use crate::syntax_kind::{self, *};
-use code_grammar::{ast_node, AstNode, Lexer, Location, Parser, SyntaxError, SyntaxNode, TreeArc};
+use code_grammar::{ast_node, AstNode, Lexer, Location, Parser, SyntaxElement, SyntaxError, SyntaxNode, SyntaxToken};
+use code_grammar::{TextRange, TextUnit, TransparentNewType, TreeArc};
 use code_grammar::parser::ParseConfig;
 use html_grammar::ast as html;
 use html_grammar::lexer::HtmlLexer;
@@ -15,6 +16,38 @@ ast_node!(AttributeBinding, ATTRIBUTE_BINDING);
 ast_node!(AttributeListener, ATTRIBUTE_LISTENER);
 ast_node!(AttributeModifier, ATTRIBUTE_MODIFIER);
 ast_node!(AttributeKey, ATTRIBUTE_KEY);
+ast_node!(AttributeSlot, ATTRIBUTE_SLOT);
+
+/// A vue directive, i.e. any attribute starting with `v-` or written with one of the
+/// shorthands (e.g. `v-if="ok"`, `:title="title"`, `@click.prevent="submit"` or `#header="{ title }"`).
+#[derive(Debug, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct Directive { pub syntax: SyntaxNode }
+unsafe impl TransparentNewType for Directive {
+    type Repr = SyntaxNode;
+}
+impl AstNode for Directive {
+    fn cast(syntax: &SyntaxNode) -> Option<&Self> {
+        match syntax.kind() {
+            ATTRIBUTE_BINDING | ATTRIBUTE_LISTENER | ATTRIBUTE_SLOT => Some(Directive::from_repr(syntax)),
+            ATTRIBUTE if syntax.first_token().map(|token| token.text().starts_with("v-")).unwrap_or(false) => {
+                Some(Directive::from_repr(syntax))
+            }
+            _ => None,
+        }
+    }
+
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn downcast(ast: &impl AstNode) -> Option<&Self> {
+        Self::cast(ast.syntax())
+    }
+}
+impl ToOwned for Directive {
+    type Owned = TreeArc<Self>;
+    fn to_owned(&self) -> TreeArc<Self> {
+        TreeArc::cast(self.syntax.to_owned())
+    }
+}
 
 impl Component {
     fn new(root: TreeArc<SyntaxNode>) -> TreeArc<Component> {
@@ -31,6 +64,8 @@ impl Component {
             max_rollback_size: 4,
             preserve_comments: true,
             preserve_whitespace: true,
+            // N.B. unlike html, tag names are case-sensitive in templates (e.g. `<Input>` is a component)
+            keywords_ignore_case: false,
         });
         let (root, remainder) = parser.parse(grammar::component);
         let node = Component::new(root.to_owned());
@@ -65,3 +100,179 @@ impl Script {
         self.syntax.children().find_map(html::Script::cast)
     }
 }
+
+impl Directive {
+    /// The directive's name without the `v-` prefix (e.g. `bind` for both `v-bind:title` and `:title`).
+    pub fn name(&self) -> &str {
+        match self.syntax.kind() {
+            ATTRIBUTE_BINDING => "bind",
+            ATTRIBUTE_LISTENER => "on",
+            ATTRIBUTE_SLOT => "slot",
+            _ => {
+                // N.B. modifiers are part of the attribute's name (e.g. `v-model.trim`)
+                let text = self.syntax.first_token().map(|token| token.text().as_str()).unwrap_or_default();
+                text[2..].split('.').next().unwrap_or_default()
+            }
+        }
+    }
+
+    /// The directive's argument (e.g. `title` in `:title` or `key` in `:[key]`).
+    pub fn argument(&self) -> Option<&AttributeKey> {
+        self.syntax.children().find_map(AttributeKey::cast)
+    }
+
+    /// Returns true if the directive's argument is an expression (e.g. `:[key]`).
+    pub fn is_dynamic(&self) -> bool {
+        self.argument().map(AttributeKey::is_dynamic).unwrap_or(false)
+    }
+
+    /// The directive's modifiers with the range of each modifier's name (e.g. `prevent` in `@click.prevent`).
+    ///
+    /// N.B. the lexer includes static modifiers in an attribute's name (e.g. `click.prevent`), so modifiers
+    ///      are only parsed as an `ATTRIBUTE_MODIFIER` after a dynamic argument (e.g. `@[event].prevent`).
+    pub fn modifiers(&self) -> Vec<(&str, TextRange)> {
+        let mut modifiers = Vec::new();
+        let name = match (self.syntax.kind(), self.argument()) {
+            (_, Some(key)) => key.name_token(),
+            (ATTRIBUTE, None) => self.syntax.first_token(),
+            _ => None,
+        };
+        if let Some(token) = name {
+            let mut parts = token.text().as_str().split('.');
+            let mut pos = token.range().start() + TextUnit::of_str(parts.next().unwrap_or_default());
+            for part in parts {
+                pos += TextUnit::of_char('.');
+                modifiers.push((part, TextRange::offset_len(pos, TextUnit::of_str(part))));
+                pos += TextUnit::of_str(part);
+            }
+        }
+        for modifier in self.syntax.children().filter_map(AttributeModifier::cast) {
+            if let Some(token) = modifier.name() {
+                modifiers.push((token.text().as_str(), token.range()));
+            }
+        }
+        modifiers
+    }
+
+    /// The directive's value token (e.g. `"submit"` in `@click="submit"`), including quotes.
+    pub fn value_token(&self) -> Option<SyntaxToken> {
+        self.syntax.children_with_tokens()
+            .skip_while(|syn| syn.kind() != EQ)
+            .skip(1) // eat `EQ`
+            .skip_while(|syn| syn.kind() == WS)
+            .next()
+            .and_then(|syn| match syn {
+                SyntaxElement::Token(token) => Some(token),
+                _ => None,
+            })
+    }
+
+    /// The directive's value without quotes (e.g. `submit` in `@click="submit"`).
+    pub fn value(&self) -> Option<&str> {
+        let token = self.value_token()?;
+        let text = token.text().as_str();
+        if token.kind() == QUOTED {
+            Some(&text[1 .. text.len() - 1])
+        } else {
+            Some(text)
+        }
+    }
+
+    /// The range of the directive's value without quotes.
+    pub fn value_range(&self) -> Option<TextRange> {
+        let token = self.value_token()?;
+        let range = token.range();
+        if token.kind() == QUOTED {
+            Some(TextRange::from_to(range.start() + TextUnit::of_char('"'), range.end() - TextUnit::of_char('"')))
+        } else {
+            Some(range)
+        }
+    }
+}
+
+impl AttributeKey {
+    /// Returns true if the key is an expression in brackets (e.g. `[key]`).
+    pub fn is_dynamic(&self) -> bool {
+        self.syntax.first_token().map(|token| token.kind()) == Some(L_SQUARE)
+    }
+
+    /// The key's name without any modifiers, or `None` if the key is dynamic.
+    pub fn name(&self) -> Option<&str> {
+        let token = self.name_token()?;
+        Some(token.text().as_str().split('.').next().unwrap_or_default())
+    }
+
+    /// The range of the key's name without any modifiers, or of the expression between the brackets.
+    pub fn range(&self) -> TextRange {
+        match self.name_token() {
+            Some(token) => {
+                let name = token.text().as_str().split('.').next().unwrap_or_default();
+                TextRange::offset_len(token.range().start(), TextUnit::of_str(name))
+            }
+            None => {
+                let range = self.syntax.range();
+                let open = self.syntax.first_token().map(|token| token.range().end()).unwrap_or(range.start());
+                let close = match self.syntax.last_token() {
+                    Some(token) if token.kind() == R_SQUARE => token.range().start(),
+                    _ => range.end(),
+                };
+                TextRange::from_to(open, close.max(open))
+            }
+        }
+    }
+
+    fn name_token(&self) -> Option<SyntaxToken> {
+        self.syntax.first_token().filter(|token| token.kind() == TAG_NAME)
+    }
+}
+
+impl AttributeModifier {
+    pub fn name(&self) -> Option<SyntaxToken> {
+        self.syntax.children_with_tokens().find_map(|syn| match syn {
+            SyntaxElement::Token(token) if token.kind() == TAG_NAME => Some(token),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use code_grammar::SyntaxNodeExt;
+
+    fn directives(text: &str) -> Vec<(String, Option<String>, bool, Vec<String>, Option<String>)> {
+        let (component, _) = Component::parse(text);
+        let template = component.template().unwrap();
+        template.syntax.descendants()
+            .filter_map(Directive::cast)
+            .map(|directive| (
+                directive.name().to_string(),
+                directive.argument().and_then(AttributeKey::name).map(String::from),
+                directive.is_dynamic(),
+                directive.modifiers().into_iter().map(|(modifier, _)| modifier.to_string()).collect(),
+                directive.value().map(String::from),
+            ))
+            .collect()
+    }
+
+    #[test]
+    fn test_directive_forms() {
+        let text = r#"<template><div v-if="ok" :title.camel="title" @[event].prevent="submit" #header="{ title }" id="x"></div></template>"#;
+        assert_eq!(directives(text), vec![
+            ("if".to_string(), None, false, vec![], Some("ok".to_string())),
+            ("bind".to_string(), Some("title".to_string()), false, vec!["camel".to_string()], Some("title".to_string())),
+            ("on".to_string(), None, true, vec!["prevent".to_string()], Some("submit".to_string())),
+            ("slot".to_string(), Some("header".to_string()), false, vec![], Some("{ title }".to_string())),
+        ]);
+
+        let text = r#"<template><form v-on:submit.prevent="save" v-bind:title.camel="title" v-slot:footer v-my-dir:arg.a.b="value" v-on:[event].stop="go" v-on="listeners"></form></template>"#;
+        assert_eq!(directives(text), vec![
+            ("on".to_string(), Some("submit".to_string()), false, vec!["prevent".to_string()], Some("save".to_string())),
+            ("bind".to_string(), Some("title".to_string()), false, vec!["camel".to_string()], Some("title".to_string())),
+            ("slot".to_string(), Some("footer".to_string()), false, vec![], None),
+            ("my-dir".to_string(), Some("arg".to_string()), false, vec!["a".to_string(), "b".to_string()], Some("value".to_string())),
+            ("on".to_string(), None, true, vec!["stop".to_string()], Some("go".to_string())),
+            ("on".to_string(), None, false, vec![], Some("listeners".to_string())),
+        ]);
+    }
+}
@@ -12,6 +12,8 @@ use crate::syntax_kind::*;
 use code_grammar::{catch, tokenset, Parser, TokenSet};
 use code_grammar::parser::Continue;
 
+pub use crate::grammar_ext::element_pattern;
+
 pub fn component(p: &mut Parser) -> Option<Continue> {
     let _marker = p.start();
     let _ok = catch!({
@@ -61,7 +63,7 @@ pub fn component_template(p: &mut Parser) -> Option<Continue> {
         p.expect(L_ANGLE)?;
         template_tag(p)?;
         p.eat(WS);
-        while p.at_ts(&tokenset![AT, COLON, TAG_NAME]) {
+        while p.at_ts(&tokenset![AT, COLON, HASH, TAG_NAME]) {
             let _checkpoint = p.checkpoint_ambiguous();
             catch!({
                 attribute(p)?;
@@ -98,7 +100,7 @@ pub fn component_script(p: &mut Parser) -> Option<Continue> {
         p.expect(L_ANGLE)?;
         script_tag(p)?;
         p.eat(WS);
-        while p.at_ts(&tokenset![AT, COLON, TAG_NAME]) {
+        while p.at_ts(&tokenset![AT, COLON, HASH, TAG_NAME]) {
             let _checkpoint = p.checkpoint_ambiguous();
             catch!({
                 attribute(p)?;
@@ -137,7 +139,7 @@ pub fn component_style(p: &mut Parser) -> Option<Continue> {
         p.expect(L_ANGLE)?;
         style_tag(p)?;
         p.eat(WS);
-        while p.at_ts(&tokenset![AT, COLON, TAG_NAME]) {
+        while p.at_ts(&tokenset![AT, COLON, HASH, TAG_NAME]) {
             let _checkpoint = p.checkpoint_ambiguous();
             catch!({
                 attribute(p)?;
@@ -195,24 +197,9 @@ pub fn style_tag(p: &mut Parser) -> Option<Continue> {
 }
 
 pub fn template_content(p: &mut Parser) -> Option<Continue> {
-    if p.at_ts(&tokenset![TEXT, WHITESPACE]) {
-        html_chardata(p)?;
-    }
-    while p.at_ts(&tokenset![COMMENT, L_ANGLE, MUSTACHE]) {
+    while p.at_ts(&tokenset![COMMENT, L_ANGLE, MUSTACHE, TEXT, WHITESPACE]) || ((p.nth_at_keyword(1, "area") || p.nth_at_keyword(1, "base") || p.nth_at_keyword(1, "br") || p.nth_at_keyword(1, "col") || p.nth_at_keyword(1, "embed") || p.nth_at_keyword(1, "hr") || p.nth_at_keyword(1, "img") || p.nth_at_keyword(1, "input") || p.nth_at_keyword(1, "link") || p.nth_at_keyword(1, "meta") || p.nth_at_keyword(1, "p") || p.nth_at_keyword(1, "param") || p.nth_at_keyword(1, "source") || p.nth_at_keyword(1, "track") || p.nth_at_keyword(1, "wbr")) && p.at(L_ANGLE_SLASH)) {
         let _checkpoint = p.checkpoint_ambiguous();
-        catch!({
-            if p.at(L_ANGLE) {
-                element(p)?;
-            } else if p.at(MUSTACHE) {
-                p.bump();
-            } else if p.at(COMMENT) {
-                p.bump();
-            }
-            if p.at_ts(&tokenset![TEXT, WHITESPACE]) {
-                html_chardata(p)?;
-            }
-            Some(Continue)
-        });
+        template_content_item(p);
         if !p.commit(_checkpoint)?.is_ok() {
             break;
         }
@@ -220,38 +207,56 @@ pub fn template_content(p: &mut Parser) -> Option<Continue> {
     Some(Continue)
 }
 
-pub fn html_content(p: &mut Parser) -> Option<Continue> {
-    if p.at_ts(&tokenset![COMMENT, L_ANGLE, MUSTACHE, TEXT, WHITESPACE]) {
-        if p.at_ts(&tokenset![TEXT, WHITESPACE]) {
-            html_chardata(p)?;
-        }
-        while p.at_ts(&tokenset![COMMENT, L_ANGLE, MUSTACHE]) {
-            let _checkpoint = p.checkpoint_ambiguous();
-            catch!({
-                if p.at(L_ANGLE) {
-                    element(p)?;
-                } else if p.at(MUSTACHE) {
-                    p.bump();
-                } else if p.at(COMMENT) {
-                    p.bump();
-                }
-                if p.at_ts(&tokenset![TEXT, WHITESPACE]) {
-                    html_chardata(p)?;
-                }
-                Some(Continue)
-            });
-            if !p.commit(_checkpoint)?.is_ok() {
-                break;
-            }
-        }
+pub fn template_content_item(p: &mut Parser) -> Option<Continue> {
+    if p.at(L_ANGLE) {
+        element(p)?;
+    } else if ((p.nth_at_keyword(1, "area") || p.nth_at_keyword(1, "base") || p.nth_at_keyword(1, "br") || p.nth_at_keyword(1, "col") || p.nth_at_keyword(1, "embed") || p.nth_at_keyword(1, "hr") || p.nth_at_keyword(1, "img") || p.nth_at_keyword(1, "input") || p.nth_at_keyword(1, "link") || p.nth_at_keyword(1, "meta") || p.nth_at_keyword(1, "param") || p.nth_at_keyword(1, "source") || p.nth_at_keyword(1, "track") || p.nth_at_keyword(1, "wbr")) && p.at(L_ANGLE_SLASH)) && {
+        // try --> void_end_tag
+        let mut _checkpoint = p.checkpoint(true);
+        void_end_tag(p);
+        p.commit(_checkpoint)?.is_ok()
+    } {
+        // ok
+    } else if p.nth_at_keyword(1, "p") && p.at(L_ANGLE_SLASH) {
+        stray_end_tag(p)?;
+    } else if p.at(MUSTACHE) {
+        p.bump();
+    } else if p.at(COMMENT) {
+        p.bump();
+    } else if p.at_ts(&tokenset![TEXT, WHITESPACE]) {
+        html_chardata(p)?;
+    } else {
+        // otherwise, emit an error
+        p.expected_ts_in("template_content_item", &AT_TEMPLATE_CONTENT_ITEM)?;
+    }
+    Some(Continue)
+}
+
+pub fn html_content_item(p: &mut Parser) -> Option<Continue> {
+    if p.at(L_ANGLE) {
+        element(p)?;
+    } else if ((p.nth_at_keyword(1, "area") || p.nth_at_keyword(1, "base") || p.nth_at_keyword(1, "br") || p.nth_at_keyword(1, "col") || p.nth_at_keyword(1, "embed") || p.nth_at_keyword(1, "hr") || p.nth_at_keyword(1, "img") || p.nth_at_keyword(1, "input") || p.nth_at_keyword(1, "link") || p.nth_at_keyword(1, "meta") || p.nth_at_keyword(1, "param") || p.nth_at_keyword(1, "source") || p.nth_at_keyword(1, "track") || p.nth_at_keyword(1, "wbr")) && p.at(L_ANGLE_SLASH)) && {
+        // try --> void_end_tag
+        let mut _checkpoint = p.checkpoint(true);
+        void_end_tag(p);
+        p.commit(_checkpoint)?.is_ok()
+    } {
+        // ok
+    } else if p.nth_at_keyword(1, "p") && p.at(L_ANGLE_SLASH) {
+        stray_end_tag(p)?;
+    } else if p.at(MUSTACHE) {
+        p.bump();
+    } else if p.at(COMMENT) {
+        p.bump();
+    } else if p.at_ts(&tokenset![TEXT, WHITESPACE]) {
+        html_chardata(p)?;
     } else if p.at(SCRIPT_CONTENT) {
-        if p.at(SCRIPT_CONTENT) {
-            script_block(p)?;
-        }
+        script_block(p)?;
     } else if p.at(STYLE_CONTENT) {
-        if p.at(STYLE_CONTENT) {
-            style_block(p)?;
-        }
+        style_block(p)?;
+    } else {
+        // otherwise, emit an error
+        p.expected_ts_in("html_content_item", &AT_HTML_CONTENT_ITEM)?;
     }
     Some(Continue)
 }
@@ -297,14 +302,50 @@ pub fn attribute(p: &mut Parser) -> Option<Continue> {
         p.commit(_checkpoint)?.is_ok()
     } {
         // ok
+    } else if (p.at(HASH) || (p.at_keyword("v-slot") && p.at(TAG_NAME))) && {
+        // try --> ({ <predicate> }? TAG_NAME ':' | '#') attribute_key ((WS)? '=' (WS)? attribute_value)? #ATTRIBUTE_SLOT
+        let mut _checkpoint = p.checkpoint(true);
+        catch!({
+            let _marker = p.start();
+            let _ok = catch!({
+                if p.at_keyword("v-slot") && p.at(TAG_NAME) {
+                    p.bump();
+                    p.expect(COLON)?;
+                } else if p.at(HASH) {
+                    p.bump();
+                }
+                attribute_key(p)?;
+                if p.at_ts(&tokenset![EQ, WS]) {
+                    let mut _checkpoint = p.checkpoint(true);
+                    catch!({
+                        p.eat(WS);
+                        p.expect(EQ)?;
+                        p.eat(WS);
+                        attribute_value(p)?;
+                        Some(Continue)
+                    });
+                    p.commit(_checkpoint)?.ok();
+                }
+                Some(Continue)
+            });
+            p.complete(_marker, ATTRIBUTE_SLOT);
+            if _ok.is_none() {
+                return None;
+            }
+            Some(Continue)
+        });
+        p.commit(_checkpoint)?.is_ok()
+    } {
+        // ok
     } else if (p.at(AT) || (p.at_keyword("v-on") && p.at(TAG_NAME))) && {
-        // try --> ({ <predicate> }? TAG_NAME | '@') attribute_key (attribute_modifier)* ((WS)? '=' (WS)? attribute_value)? #ATTRIBUTE_LISTENER
+        // try --> ({ <predicate> }? TAG_NAME ':' | '@') attribute_key (attribute_modifier)* ((WS)? '=' (WS)? attribute_value)? #ATTRIBUTE_LISTENER
         let mut _checkpoint = p.checkpoint(true);
         catch!({
             let _marker = p.start();
             let _ok = catch!({
                 if p.at_keyword("v-on") && p.at(TAG_NAME) {
                     p.bump();
+                    p.expect(COLON)?;
                 } else if p.at(AT) {
                     p.bump();
                 }
@@ -338,6 +379,44 @@ pub fn attribute(p: &mut Parser) -> Option<Continue> {
         p.commit(_checkpoint)?.is_ok()
     } {
         // ok
+    } else if p.at(TAG_NAME) && {
+        // try --> TAG_NAME ':' attribute_key (attribute_modifier)* ((WS)? '=' (WS)? attribute_value)? #ATTRIBUTE
+        let mut _checkpoint = p.checkpoint(true);
+        catch!({
+            let _marker = p.start();
+            let _ok = catch!({
+                p.bump();
+                p.expect(COLON)?;
+                attribute_key(p)?;
+                while p.at(DOT) {
+                    let _checkpoint = p.checkpoint_ambiguous();
+                    attribute_modifier(p);
+                    if !p.commit(_checkpoint)?.is_ok() {
+                        break;
+                    }
+                }
+                if p.at_ts(&tokenset![EQ, WS]) {
+                    let mut _checkpoint = p.checkpoint(true);
+                    catch!({
+                        p.eat(WS);
+                        p.expect(EQ)?;
+                        p.eat(WS);
+                        attribute_value(p)?;
+                        Some(Continue)
+                    });
+                    p.commit(_checkpoint)?.ok();
+                }
+                Some(Continue)
+            });
+            p.complete(_marker, ATTRIBUTE);
+            if _ok.is_none() {
+                return None;
+            }
+            Some(Continue)
+        });
+        p.commit(_checkpoint)?.is_ok()
+    } {
+        // ok
     } else if p.at(TAG_NAME) && {
         // try --> TAG_NAME (WS)? '=' (WS)? attribute_value #ATTRIBUTE
         let mut _checkpoint = p.checkpoint(true);
@@ -366,7 +445,7 @@ pub fn attribute(p: &mut Parser) -> Option<Continue> {
         p.complete(_marker, ATTRIBUTE);
     } else {
         // otherwise, emit an error
-        p.expected_ts_in("attribute", &tokenset![AT, COLON, TAG_NAME])?;
+        p.expected_ts_in("attribute", &tokenset![AT, COLON, HASH, TAG_NAME])?;
     }
     Some(Continue)
 }
@@ -382,14 +461,18 @@ pub fn attribute_modifier(p: &mut Parser) -> Option<Continue> {
     _ok
 }
 
+pub fn attribute_key_token(p: &mut Parser) -> Option<Continue> {
+    p.expect_ts(&AT_ATTRIBUTE_KEY_TOKEN)
+}
+
 pub fn attribute_key(p: &mut Parser) -> Option<Continue> {
     if p.at(L_SQUARE) {
         let _marker = p.start();
         let _ok = catch!({
             p.bump();
-            p.eat(WS);
-            p.expect(TAG_NAME)?;
-            p.eat(WS);
+            while p.at_ts(&AT_ATTRIBUTE_KEY_TOKEN) {
+                attribute_key_token(p)?;
+            }
             p.expect(R_SQUARE)?;
             Some(Continue)
         });
@@ -414,69 +497,19 @@ pub fn element(p: &mut Parser) -> Option<Continue> {
     _ok
 }
 
-pub fn element_pattern(p: &mut Parser) -> Option<Continue> {
-    p.expect(L_ANGLE)?;
-    if ((p.at_keyword("area") || p.at_keyword("base") || p.at_keyword("br") || p.at_keyword("col") || p.at_keyword("embed") || p.at_keyword("hr") || p.at_keyword("img") || p.at_keyword("input") || p.at_keyword("link") || p.at_keyword("meta") || p.at_keyword("param") || p.at_keyword("source") || p.at_keyword("track") || p.at_keyword("wbr")) && p.at(TAG_NAME)) && {
-        // try --> empty_element_tag_name (WS)? (attribute (WS)?)* ('>' | '/>')
-        let mut _checkpoint = p.checkpoint(true);
-        catch!({
-            empty_element_tag_name(p)?;
-            p.eat(WS);
-            while p.at_ts(&tokenset![AT, COLON, TAG_NAME]) {
-                let _checkpoint = p.checkpoint_ambiguous();
-                catch!({
-                    attribute(p)?;
-                    p.eat(WS);
-                    Some(Continue)
-                });
-                if !p.commit(_checkpoint)?.is_ok() {
-                    break;
-                }
-            }
-            p.expect_ts(&tokenset![R_ANGLE, SLASH_R_ANGLE])?;
-            Some(Continue)
-        });
-        p.commit(_checkpoint)?.is_ok()
-    } {
-        // ok
-    } else if p.at(TAG_NAME) {
+pub fn end_tag(p: &mut Parser) -> Option<Continue> {
+    if p.at(L_ANGLE) {
+        p.bump();
+        p.expect(SLASH)?;
+    } else if p.at(L_ANGLE_SLASH) {
         p.bump();
-        p.eat(WS);
-        while p.at_ts(&tokenset![AT, COLON, TAG_NAME]) {
-            let _checkpoint = p.checkpoint_ambiguous();
-            catch!({
-                attribute(p)?;
-                p.eat(WS);
-                Some(Continue)
-            });
-            if !p.commit(_checkpoint)?.is_ok() {
-                break;
-            }
-        }
-        if p.at(SLASH_R_ANGLE) {
-            p.bump();
-        } else if p.at(R_ANGLE) {
-            p.bump();
-            html_content(p)?;
-            if p.at(L_ANGLE) {
-                p.bump();
-                p.expect(SLASH)?;
-            } else if p.at(L_ANGLE_SLASH) {
-                p.bump();
-            } else {
-                p.expected_ts_in("element_pattern", &tokenset![L_ANGLE, L_ANGLE_SLASH])?;
-            }
-            p.eat(WS);
-            p.expect(TAG_NAME)?;
-            p.eat(WS);
-            p.expect(R_ANGLE)?;
-        } else {
-            p.expected_ts_in("element_pattern", &tokenset![R_ANGLE, SLASH_R_ANGLE])?;
-        }
     } else {
-        // otherwise, emit an error
-        p.expected_in("element_pattern", TAG_NAME)?;
+        p.expected_ts_in("end_tag", &tokenset![L_ANGLE, L_ANGLE_SLASH])?;
     }
+    p.eat(WS);
+    p.expect(TAG_NAME)?;
+    p.eat(WS);
+    p.expect(R_ANGLE)?;
     Some(Continue)
 }
 
@@ -520,6 +553,71 @@ pub fn attribute_value(p: &mut Parser) -> Option<Continue> {
     p.expect_ts(&tokenset![QUOTED, TAG_NAME])
 }
 
+pub fn void_end_tag(p: &mut Parser) -> Option<Continue> {
+    let _marker = p.start();
+    let _ok = catch!({
+        void_end_tag_open(p)?;
+        p.expect(TAG_NAME)?;
+        p.eat(WS);
+        p.expect(R_ANGLE)?;
+        Some(Continue)
+    });
+    p.complete(_marker, VOID_END_TAG);
+    _ok
+}
+
+pub fn stray_end_tag(p: &mut Parser) -> Option<Continue> {
+    let _marker = p.start();
+    let _ok = catch!({
+        if !(p.nth_at_keyword(1, "p")) {
+            p.error("expected to be nth at keyword 1 (with 'p')")?;
+        }
+        p.expect(L_ANGLE_SLASH)?;
+        p.expect(TAG_NAME)?;
+        p.eat(WS);
+        p.expect(R_ANGLE)?;
+        Some(Continue)
+    });
+    p.complete(_marker, STRAY_END_TAG);
+    _ok
+}
+
+pub fn void_end_tag_open(p: &mut Parser) -> Option<Continue> {
+    if p.nth_at_keyword(1, "area") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "base") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "br") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "col") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "embed") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "hr") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "img") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "input") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "link") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "meta") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "param") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "source") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "track") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else if p.nth_at_keyword(1, "wbr") && p.at(L_ANGLE_SLASH) {
+        p.bump();
+    } else {
+        // otherwise, emit an error
+        p.expected_in("void_end_tag_open", L_ANGLE_SLASH)?;
+    }
+    Some(Continue)
+}
+
 pub fn html_chardata(p: &mut Parser) -> Option<Continue> {
     p.expect_ts(&tokenset![TEXT, WHITESPACE])
 }
@@ -541,3 +639,7 @@ pub fn style_block(p: &mut Parser) -> Option<Continue> {
     p.complete(_marker, STYLE_BLOCK);
     _ok
 }
+
+pub(crate) const AT_ATTRIBUTE_KEY_TOKEN: TokenSet = tokenset![AMPERSAND, ASTERISK, AT, BANG, CARET, COLON, DOLLAR, DOT, ERROR, L_CURLY, L_PAREN, PERCENT, PIPE, QUESTION, QUOTED, R_CURLY, R_PAREN, SLASH, TAG_NAME, TILDE, WS];
+pub(crate) const AT_HTML_CONTENT_ITEM: TokenSet = tokenset![COMMENT, L_ANGLE, L_ANGLE_SLASH, MUSTACHE, SCRIPT_CONTENT, STYLE_CONTENT, TEXT, WHITESPACE];
+pub(crate) const AT_TEMPLATE_CONTENT_ITEM: TokenSet = tokenset![COMMENT, L_ANGLE, L_ANGLE_SLASH, MUSTACHE, TEXT, WHITESPACE];
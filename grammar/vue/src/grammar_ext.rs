@@ -0,0 +1,16 @@
+use crate::grammar::*;
+use crate::syntax_kind::*;
+use code_grammar::{tokenset, Parser};
+use code_grammar::parser::Continue;
+use html_grammar::grammar_ext::{element_pattern_with, ElementRules};
+
+const VUE_ELEMENT: ElementRules = ElementRules {
+    attribute,
+    attribute_start: tokenset![AT, COLON, HASH, TAG_NAME],
+    content_item: html_content_item,
+    content_start: AT_HTML_CONTENT_ITEM,
+};
+
+pub fn element_pattern(p: &mut Parser) -> Option<Continue> {
+    element_pattern_with(p, &VUE_ELEMENT)
+}
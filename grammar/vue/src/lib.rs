@@ -1,3 +1,5 @@
+mod grammar_ext;
+
 pub mod ast;
 pub mod grammar;
-pub mod syntax_kind;
\ No newline at end of file
+pub mod syntax_kind;
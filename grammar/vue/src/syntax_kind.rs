@@ -24,5 +24,6 @@ syntax_kinds! {
         ATTRIBUTE_LISTENER 105
         ATTRIBUTE_MODIFIER 106
         ATTRIBUTE_KEY 107
+        ATTRIBUTE_SLOT 108
     }
 }
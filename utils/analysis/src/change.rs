@@ -98,13 +98,17 @@ impl SourceChange {
     pub fn apply_to(self, db: &mut dyn SourceDatabase) {
         if !self.new_roots.is_empty() {
             let mut local_roots = Vec::clone(&db.local_roots());
+            let mut foreign_roots = Vec::clone(&db.foreign_roots());
             for (root_id, is_local) in self.new_roots {
                 db.set_source_root(root_id, Default::default());
                 if is_local {
                     local_roots.push(root_id);
+                } else {
+                    foreign_roots.push(root_id);
                 }
             }
             db.set_local_roots(Arc::new(local_roots));
+            db.set_foreign_roots(Arc::new(foreign_roots));
         }
 
         for (root_id, root_change) in self.roots_changed {
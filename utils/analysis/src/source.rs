@@ -56,9 +56,47 @@ fn file_source(db: &impl SourceDatabase, file_id: FileId) -> SourceId {
     db.source_id(Source {
         repr: SourceRepr::File(file_id),
         text: db.file_text(file_id),
+        origin: None,
     })
 }
 
+/// Maps a range in a source to the range in the file that it originates from.
+///
+/// Returns `None` if the source isn't derived from a file.
+pub fn original_range(db: &impl SourceDatabase, source_id: SourceId, range: TextRange) -> Option<FileRange> {
+    let source = db.lookup_source_id(source_id);
+    match (source.as_file(), source.origin) {
+        (Some(file_id), _) => Some(FileRange { file_id, range }),
+        (None, Some(origin)) => original_range(db, origin.parent, range + origin.range.start()),
+        (None, None) => None,
+    }
+}
+
+/// Maps an offset in a source to the position in the file that it originates from, like `original_range`.
+pub fn original_position(db: &impl SourceDatabase, source_id: SourceId, offset: TextUnit) -> Option<FilePosition> {
+    let FileRange { file_id, range } = original_range(db, source_id, TextRange::offset_len(offset, 0.into()))?;
+    Some(FilePosition { file_id, offset: range.start() })
+}
+
+/// Maps a range in the file that a source originates from to the range in the source.
+///
+/// Returns `None` if the range isn't contained by the source's text.
+pub fn source_range(db: &impl SourceDatabase, source_id: SourceId, range: TextRange) -> Option<TextRange> {
+    let source = db.lookup_source_id(source_id);
+    match (source.as_file(), source.origin) {
+        (Some(_), _) => Some(range),
+        (None, Some(origin)) => {
+            let range = source_range(db, origin.parent, range)?;
+            if !range.is_subrange(&origin.range) {
+                return None;
+            }
+            let start = origin.range.start();
+            Some(TextRange::from_to(range.start() - start, range.end() - start))
+        }
+        (None, None) => None,
+    }
+}
+
 fn source_text(db: &impl SourceDatabase, source_id: SourceId) -> Arc<String> {
     db.lookup_source_id(source_id).text
 }
@@ -130,6 +168,16 @@ macro_rules! impl_source_key {
 pub struct Source {
     repr: SourceRepr,
     text: Arc<String>,
+    origin: Option<SourceOrigin>,
+}
+
+/// Where the text of a derived source was found (e.g. the script embedded in a vue component).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct SourceOrigin {
+    /// The source that the derived source's text was copied from.
+    pub parent: SourceId,
+    /// The range of the derived source's text in the parent's text.
+    pub range: TextRange,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
@@ -143,9 +191,20 @@ impl Source {
         Source {
             repr: SourceRepr::Key(key.as_intern_id(), K::DISCRIMINANT),
             text,
+            origin: None,
         }
     }
 
+    /// Records that the source's text is a range of the `parent` source's text.
+    pub fn with_origin(mut self, parent: SourceId, range: TextRange) -> Source {
+        self.origin = Some(SourceOrigin { parent, range });
+        self
+    }
+
+    pub fn origin(&self) -> Option<SourceOrigin> {
+        self.origin
+    }
+
     pub fn as_file(&self) -> Option<FileId> {
         match self.repr {
             SourceRepr::File(id) => Some(id),
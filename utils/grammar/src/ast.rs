@@ -14,6 +14,21 @@ pub trait AstNode: TransparentNewType<Repr = SyntaxNode> + ToOwned<Owned = TreeA
     fn downcast(ast: &impl AstNode) -> Option<&Self> where Self: Sized;
 }
 
+/// Traversal helpers for `SyntaxNode` which aren't provided by `rowan` itself.
+pub trait SyntaxNodeExt {
+    /// Iterates over the node and all of its descendant nodes, in preorder.
+    fn descendants(&self) -> Box<dyn Iterator<Item = &SyntaxNode> + '_>;
+}
+
+impl SyntaxNodeExt for SyntaxNode {
+    fn descendants(&self) -> Box<dyn Iterator<Item = &SyntaxNode> + '_> {
+        Box::new(self.preorder().filter_map(|event| match event {
+            WalkEvent::Enter(node) => Some(node),
+            WalkEvent::Leave(_) => None,
+        }))
+    }
+}
+
 pub fn debug_dump<'a, Fmt>(node: &SyntaxNode, mut errors: Vec<SyntaxError>, fmt_debug: Fmt) -> String
 where
     Fmt: Fn(SyntaxKind) -> &'a str
@@ -9,7 +9,7 @@ pub mod parser;
 pub mod scan;
 pub mod syntax_kind;
 
-pub use crate::ast::AstNode;
+pub use crate::ast::{AstNode, SyntaxNodeExt};
 pub use crate::lexer::{Lexer, Token};
 pub use crate::location::Location;
 pub use crate::parser::{Parser, TokenInput, TokenSet, TreeNode};
@@ -55,6 +55,8 @@ pub struct ParseConfig {
     pub max_rollback_size: u16,
     pub preserve_comments: bool,
     pub preserve_whitespace: bool,
+    /// Whether keywords are matched ignoring ASCII case (e.g. html tag names).
+    pub keywords_ignore_case: bool,
 }
 
 impl ParseConfig {
@@ -89,6 +91,7 @@ impl Default for ParseConfig {
             max_rollback_size: 32,
             preserve_comments: false,
             preserve_whitespace: false,
+            keywords_ignore_case: false,
         }
     }
 }
@@ -101,6 +104,7 @@ pub struct Parser<'a, 'b, E: 'static + Debug + Send + Sync = String> {
     sink: TextTreeSink<'a, 'b, E>,
     steps: Cell<u32>,
     checkpoints: Rc<Cell<u32>>,
+    open_tags: Vec<&'a str>,
 }
 
 impl<'a, 'b, E: ParseError> Parser<'a, 'b, E> {
@@ -114,6 +118,7 @@ impl<'a, 'b, E: ParseError> Parser<'a, 'b, E> {
             sink: TextTreeSink::new(input),
             steps: Cell::new(0),
             checkpoints: Rc::new(Cell::new(0)),
+            open_tags: Vec::new(),
         }
     }
 
@@ -126,18 +131,28 @@ impl<'a, 'b, E: ParseError> Parser<'a, 'b, E> {
     }
 
     /// Consume the parser and build a syntax tree.
+    ///
+    /// If the grammar stopped early (e.g. after a syntax error), any nodes it didn't complete are
+    /// completed at the end of the input which was parsed, and only the first root node is kept;
+    /// the tokens after it are returned as the remainder.
     pub fn finalize(mut self) -> (TreeNode, TokenInput<'a, 'b>) {
+        let mut depth = 0;
+        let mut complete = false;
         for op in self.events {
             match op {
                 Event::Error { error } => self.sink.error(error),
+                _ if complete => {}
                 Event::StartNode { kind } if kind == TOMBSTONE => {}
                 Event::StartNode { kind } => {
                     // eprintln!("start {} {{", (self.config.debug_repr)(kind).map(|k| k.name).unwrap_or("UNKNOWN"));
-                    self.sink.start_node(kind, self.config.skip_predicate())
+                    self.sink.start_node(kind, self.config.skip_predicate());
+                    depth += 1;
                 }
                 Event::CompleteNode => {
                     // eprintln!("}}");
-                    self.sink.complete_node()
+                    self.sink.complete_node(self.config.skip_predicate());
+                    depth -= 1;
+                    complete = depth == 0;
                 }
                 Event::Span { kind, len } => {
                     // eprintln!("  @{}", (self.config.debug_repr)(kind).map(|k| k.name).unwrap_or("_"));
@@ -145,6 +160,9 @@ impl<'a, 'b, E: ParseError> Parser<'a, 'b, E> {
                 }
             }
         }
+        for _ in 0..depth {
+            self.sink.complete_node(self.config.skip_predicate());
+        }
         self.sink.finalize()
     }
 
@@ -153,7 +171,45 @@ impl<'a, 'b, E: ParseError> Parser<'a, 'b, E> {
     ///
     /// Does not consider the SyntaxKind (e.g. to detect contextual keywords)
     pub fn at_keyword(&self, kw: &str) -> bool {
-        self.source.at_keyword(self.source_pos, kw)
+        self.nth_at_keyword(0, kw)
+    }
+
+    /// Checks if the nth token after the current token is a specified keyword, like `at_keyword`.
+    pub fn nth_at_keyword(&self, n: usize, kw: &str) -> bool {
+        if self.config.keywords_ignore_case {
+            self.is_keyword(self.nth_text(n), kw)
+        } else {
+            self.source.at_keyword(self.source_pos + n, kw)
+        }
+    }
+
+    /// Checks if some text is a specified keyword, ignoring ASCII case if configured to.
+    pub fn is_keyword(&self, text: &str, kw: &str) -> bool {
+        if self.config.keywords_ignore_case {
+            text.eq_ignore_ascii_case(kw)
+        } else {
+            text == kw
+        }
+    }
+
+    /// The text of the nth token after the current token (e.g. to compare a name case-insensitively).
+    pub fn nth_text(&self, n: usize) -> &'a str {
+        self.source.token_text(self.source_pos + n)
+    }
+
+    /// The names of the tags whose content is being parsed (e.g. in markup languages), innermost last.
+    pub fn open_tags(&self) -> &[&'a str] {
+        &self.open_tags
+    }
+
+    /// Marks a tag as open while its content is parsed; it must be closed with `close_tag`.
+    pub fn open_tag(&mut self, name: &'a str) {
+        self.open_tags.push(name);
+    }
+
+    /// Closes the innermost open tag.
+    pub fn close_tag(&mut self) {
+        self.open_tags.pop();
     }
 
     /// Checks if the next token is separated by ignored tokens (e.g. whitespace or comments).
@@ -176,6 +232,11 @@ impl<'a, 'b, E: ParseError> Parser<'a, 'b, E> {
         self.current() == kind
     }
 
+    /// Checks if the nth token after the current token is `kind`.
+    pub fn nth_at(&self, n: usize, kind: SyntaxKind) -> bool {
+        self.nth(n) == kind
+    }
+
     /// Checks if the current token is `kind`.
     pub fn at_ts(&self, ts: &TokenSet) -> bool {
         ts.contains(&self.current())
@@ -483,6 +544,13 @@ impl<'a, 'b, E: ParseError> Parser<'a, 'b, E> {
         self.events.push(Event::CompleteNode);
     }
 
+    /// Adds a node which doesn't contain any tokens to the syntax tree,
+    /// to record something at the current position (e.g. an omitted end tag).
+    pub fn complete_empty(&mut self, kind: SyntaxKind) {
+        self.events.push(Event::StartNode { kind });
+        self.events.push(Event::CompleteNode);
+    }
+
     /// Finish a syntax tree node, but insert a new placeholder before it
     /// nesting the completed node within a new yet to be completed node.
     ///
@@ -106,4 +106,13 @@ impl<'t> TextTokenSource<'t> {
             tokens
         }
     }
+
+    /// The text of the token at a position, or an empty string past the end of the input.
+    pub fn token_text(&self, pos: usize) -> &'t str {
+        if pos >= self.tokens.len() {
+            return "";
+        }
+        let range = TextRange::offset_len(self.start_offsets[pos], self.tokens[pos].len);
+        &self.text[range]
+    }
 }
\ No newline at end of file
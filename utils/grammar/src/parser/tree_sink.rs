@@ -13,6 +13,7 @@ pub struct TextTreeSink<'a, 'b, E: 'static + Debug + Send + Sync> {
     token_pos: usize,
     builder: GreenNodeBuilder,
     started: bool,
+    depth: usize,
     errors: Vec<(E, Location)>,
 }
 
@@ -25,6 +26,7 @@ impl<'a, 'b, E: 'static + Debug + Send + Sync> TextTreeSink<'a, 'b, E> {
             token_pos: 0,
             builder: GreenNodeBuilder::new(),
             started: false,
+            depth: 0,
             errors: Vec::new(),
         }
     }
@@ -64,9 +66,20 @@ impl<'a, 'b, E: 'static + Debug + Send + Sync> TextTreeSink<'a, 'b, E> {
         }
         self.builder.start_node(kind);
         self.started = true;
+        self.depth += 1;
     }
 
-    pub fn complete_node(&mut self) {
+    pub fn complete_node<F>(&mut self, skip: F)
+    where
+        F: Fn(SyntaxKind) -> bool
+    {
+        // N.B. trailing whitespace and comments belong to the root, unless there are other tokens left
+        self.depth -= 1;
+        if self.depth == 0 && self.tokens[self.token_pos..].iter().all(|token| skip(token.kind)) {
+            while let Some(&token) = self.tokens.get(self.token_pos) {
+                self.advance(token.kind, token.len, 1);
+            }
+        }
         self.builder.finish_node();
     }
 
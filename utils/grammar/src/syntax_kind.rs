@@ -73,5 +73,6 @@ syntax_kinds! {
         AT 58 ("@")
         SINGLE_QUOTE 59 ("'") [APOSTROPHE]
         DOUBLE_QUOTE 60 ("\"")
+        HASH 61 ("#")
     }
 }